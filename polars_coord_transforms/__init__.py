@@ -1,9 +1,91 @@
+from datetime import timedelta
 from pathlib import Path
 import polars as pl
 from polars.plugins import register_plugin_function
 from polars.type_aliases import PolarsDataType
 
-from typing import Protocol, Iterable, cast
+from typing import Dict, Literal, Protocol, Iterable, Optional, Sequence, Union, cast
+
+NullPolicy = Literal["propagate", "zero", "raise"]
+FieldMapping = Optional[Union[Dict[str, str], Sequence[Dict[str, str]]]]
+OutputFormat = Literal["struct", "array"]
+
+_XYZ_FIELDS = ["x", "y", "z"]
+_LLA_FIELDS = ["lon", "lat", "alt"]
+_QUAT_FIELDS = ["x", "y", "z", "w"]
+_VERTICES_FIELDS = [f"v{i}_{coord}" for i in range(4) for coord in ("lon", "lat")]
+_NON_FLOAT_FIELDS = {"zone_number": pl.UInt8, "zone_letter": pl.Utf8, "hemisphere": pl.Utf8}
+_UTM_FULL_FIELDS = [
+    "x", "y", "z", "zone_number", "zone_letter", "hemisphere", "convergence", "scale_factor"
+]
+
+
+def _fields_kwargs(fields: FieldMapping) -> dict:
+    if fields is None:
+        return {"fields": []}
+    if isinstance(fields, dict):
+        return {"fields": [dict(fields)]}
+    return {"fields": [dict(mapping) for mapping in fields]}
+
+
+def _check_output_dtype(output_dtype: PolarsDataType) -> None:
+    if output_dtype not in (pl.Float32, pl.Float64):
+        raise ValueError("`output_dtype` parameter must be pl.Float32 or pl.Float64!")
+
+
+def _cast_output(expr: pl.Expr, output_dtype: PolarsDataType) -> pl.Expr:
+    # Kernels always compute in Float64; Float32 output is a cast of their result.
+    _check_output_dtype(output_dtype)
+    return expr if output_dtype == pl.Float64 else expr.cast(output_dtype)
+
+
+def _finalize_output(
+    expr: pl.Expr,
+    names: Iterable[str],
+    output_fields: Optional[Dict[str, str]],
+    output_dtype: PolarsDataType,
+    output_format: OutputFormat = "struct",
+) -> pl.Expr:
+    _check_output_dtype(output_dtype)
+    if output_format not in ("struct", "array"):
+        raise ValueError("`output_format` parameter must be 'struct' or 'array'!")
+    if output_dtype == pl.Float32:
+        expr = expr.cast(
+            pl.Struct([pl.Field(name, _NON_FLOAT_FIELDS.get(name, pl.Float32)) for name in names])
+        )
+    if output_format == "array":
+        if output_fields:
+            raise ValueError("`output_fields` cannot be used with output_format='array'!")
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="struct_to_array",
+            args=expr,
+            is_elementwise=True
+        )
+    if not output_fields:
+        return expr
+    return expr.struct.rename_fields([output_fields.get(name, name) for name in names])
+
+
+def _check_s2_level(level: int, min_level: int = 1) -> None:
+    if level < min_level or level > 30:
+        raise ValueError(f"`level` parameter must be between {min_level} and 30!")
+
+
+def _covering_kwargs(
+    min_level: int, max_level: int, max_cells: int, interior: bool, fields: FieldMapping
+) -> dict:
+    if not 0 <= min_level <= max_level <= 30:
+        raise ValueError("`min_level` and `max_level` must satisfy 0 <= min_level <= max_level <= 30!")
+    if max_cells < 1:
+        raise ValueError("`max_cells` parameter must be positive!")
+    return {
+        "min_level": min_level,
+        "max_level": max_level,
+        "max_cells": max_cells,
+        "interior": interior,
+        **_fields_kwargs(fields),
+    }
 
 
 @pl.api.register_expr_namespace("s2")
@@ -11,40 +93,242 @@ class S2NameSpace:
     def __init__(self, expr: pl.Expr):
         self._expr = expr
 
-    def lonlat_to_cellid(self, level: int = 30) -> pl.Expr:
-        if level < 1 or level > 30:
-            raise ValueError("`level` parameter must be between 1 and 30!")
+    def lonlat_to_cellid(
+        self,
+        level: int = 30,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        _check_s2_level(level)
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="lonlat_to_cellid",
             args=self._expr,
-            kwargs={"level": level},
+            kwargs={"level": level, **_fields_kwargs(fields)},
             is_elementwise=True
         )
 
-    def cellid_to_lonlat(self) -> pl.Expr:
-        return register_plugin_function(
+    def cellid_to_lonlat(
+        self,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="cellid_to_lonlat",
             args=self._expr,
             is_elementwise=True
         )
+        return _finalize_output(expr, ["lon", "lat"], output_fields, output_dtype, output_format)
 
-    def cell_contains_point(self, point: pl.Expr) -> pl.Expr:
+    def cell_contains_point(
+        self,
+        point: pl.Expr,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="cell_contains_point",
             args=[self._expr, point],
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
 
-    def cellid_to_vertices(self) -> pl.Expr:
-        return register_plugin_function(
+    def cellid_to_vertices(
+        self,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="cellid_to_vertices",
             args=self._expr,
             is_elementwise=True
         )
+        return _finalize_output(expr, _VERTICES_FIELDS, output_fields, output_dtype, output_format)
+
+    def cell_area(
+        self,
+        kind: Literal["approx", "exact", "average"] = "approx",
+        unit: Literal["steradians", "km2", "m2"] = "km2",
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cell_area",
+            args=self._expr,
+            kwargs={"kind": kind, "unit": unit},
+            is_elementwise=True
+        )
+        return _cast_output(expr, output_dtype)
+
+    def cellid_to_token(self, invalid: Literal["null", "raise"] = "null") -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_to_token",
+            args=self._expr,
+            kwargs={"invalid": invalid},
+            is_elementwise=True
+        )
+
+    def token_to_cellid(self, invalid: Literal["null", "raise"] = "null") -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="token_to_cellid",
+            args=self._expr,
+            kwargs={"invalid": invalid},
+            is_elementwise=True
+        )
+
+    def cellid_level(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_level",
+            args=self._expr,
+            is_elementwise=True
+        )
+
+    def parent(self, level: int) -> pl.Expr:
+        _check_s2_level(level, min_level=0)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_parent",
+            args=self._expr,
+            kwargs={"level": level},
+            is_elementwise=True
+        )
+
+    def range_min(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_range_min",
+            args=self._expr,
+            is_elementwise=True
+        )
+
+    def range_max(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_range_max",
+            args=self._expr,
+            is_elementwise=True
+        )
+
+    def children(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_children",
+            args=self._expr,
+            is_elementwise=True
+        )
+
+    def edge_neighbors(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_edge_neighbors",
+            args=self._expr,
+            is_elementwise=True
+        )
+
+    def all_neighbors(self, level: Optional[int] = None) -> pl.Expr:
+        if level is not None:
+            _check_s2_level(level, min_level=0)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_all_neighbors",
+            args=self._expr,
+            kwargs={"level": level},
+            is_elementwise=True
+        )
+
+    def vertex_neighbors(self, level: Optional[int] = None) -> pl.Expr:
+        if level is not None:
+            _check_s2_level(level, min_level=0)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cellid_vertex_neighbors",
+            args=self._expr,
+            kwargs={"level": level},
+            is_elementwise=True
+        )
+
+    def polygon_covering(
+        self,
+        min_level: int = 0,
+        max_level: int = 30,
+        max_cells: int = 8,
+        interior: bool = False,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="polygon_covering",
+            args=self._expr,
+            kwargs=_covering_kwargs(min_level, max_level, max_cells, interior, fields),
+            is_elementwise=True
+        )
+
+    def cap_covering(
+        self,
+        radius: Union[pl.Expr, float],
+        min_level: int = 0,
+        max_level: int = 30,
+        max_cells: int = 8,
+        interior: bool = False,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        if not isinstance(radius, pl.Expr):
+            radius = pl.lit(radius, dtype=pl.Float64)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="cap_covering",
+            args=[self._expr, radius],
+            kwargs=_covering_kwargs(min_level, max_level, max_cells, interior, fields),
+            is_elementwise=True
+        )
+
+    def rect_covering(
+        self,
+        hi: pl.Expr,
+        min_level: int = 0,
+        max_level: int = 30,
+        max_cells: int = 8,
+        interior: bool = False,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="rect_covering",
+            args=[self._expr, hi],
+            kwargs=_covering_kwargs(min_level, max_level, max_cells, interior, fields),
+            is_elementwise=True
+        )
+
+
+
+def _ellipsoid_kwargs(ellipsoid: Union[str, Dict[str, float]]) -> dict:
+    if isinstance(ellipsoid, str):
+        return {"ellipsoid": ellipsoid, "semi_major_axis": None, "flattening": None}
+    if "a" not in ellipsoid or "f" not in ellipsoid:
+        raise ValueError("custom `ellipsoid` must be a dict with keys 'a' and 'f'!")
+    return {
+        "ellipsoid": "custom",
+        "semi_major_axis": float(ellipsoid["a"]),
+        "flattening": float(ellipsoid["f"]),
+    }
+
+
+def _utm_zone_kwargs(zone: Union[int, str, None], zone_exceptions: bool) -> dict:
+    if zone is None:
+        return {"zone": None, "zone_mode": "point", "zone_exceptions": zone_exceptions}
+    if isinstance(zone, str):
+        if zone not in ("first", "majority"):
+            raise ValueError("`zone` parameter must be an integer, 'first' or 'majority'!")
+        return {"zone": None, "zone_mode": zone, "zone_exceptions": zone_exceptions}
+    if zone < 1 or zone > 60:
+        raise ValueError("`zone` parameter must be between 1 and 60!")
+    return {"zone": zone, "zone_mode": "fixed", "zone_exceptions": zone_exceptions}
 
 
 @pl.api.register_expr_namespace("transform")
@@ -52,138 +336,913 @@ class TransformNameSpace:
     def __init__(self, expr: pl.Expr):
         self._expr = expr
 
-    def map_to_ecef(self, rotation: pl.Expr, offset: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def map_to_ecef(
+        self,
+        rotation: pl.Expr,
+        offset: pl.Expr,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="map_to_ecef",
             args=[self._expr, rotation, offset],
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
 
-    def ecef_to_map(self, rotation: pl.Expr, offset: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def ecef_to_map(
+        self,
+        rotation: pl.Expr,
+        offset: pl.Expr,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="ecef_to_map",
             args=[self._expr, rotation, offset],
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
 
-    def ecef_to_lla(self) -> pl.Expr:
-        return register_plugin_function(
+    def ecef_to_lla(
+        self,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="ecef_to_lla",
             args=self._expr,
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
             is_elementwise=True
         )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
 
-    def lla_to_ecef(self) -> pl.Expr:
-        return register_plugin_function(
+    def lla_to_ecef(
+        self,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="lla_to_ecef",
             args=self._expr,
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
             is_elementwise=True
         )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
     
-    def lla_to_utm(self) -> pl.Expr:
-        return register_plugin_function(
+    def ecef_to_enu(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="ecef_to_enu",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def enu_to_ecef(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="enu_to_ecef",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def lla_to_enu(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="lla_to_enu",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def enu_to_lla(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="enu_to_lla",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
+
+    def ecef_to_ned(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="ecef_to_ned",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def ned_to_ecef(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="ned_to_ecef",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def lla_to_ned(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="lla_to_ned",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def ned_to_lla(
+        self,
+        origin: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="ned_to_lla",
+            args=[self._expr, origin],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
+
+    def lla_to_aer(
+        self,
+        observer: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="lla_to_aer",
+            args=[self._expr, observer],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, ["azimuth", "elevation", "range"], output_fields, output_dtype, output_format)
+
+    def aer_to_lla(
+        self,
+        observer: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="aer_to_lla",
+            args=[self._expr, observer],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
+
+    def destination(
+        self,
+        bearing: Union[float, pl.Expr],
+        distance: Union[float, pl.Expr],
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        if not isinstance(bearing, pl.Expr):
+            bearing = pl.lit(bearing, dtype=pl.Float64)
+        if not isinstance(distance, pl.Expr):
+            distance = pl.lit(distance, dtype=pl.Float64)
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="destination",
+            args=[self._expr, bearing, distance],
+            kwargs={**_ellipsoid_kwargs(ellipsoid), **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
+
+    def lla_to_utm(
+        self,
+        zone: Union[int, str, None] = None,
+        zone_exceptions: bool = True,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="lla_to_utm",
             args=self._expr,
-            is_elementwise=True
+            kwargs={**_utm_zone_kwargs(zone, zone_exceptions), **_fields_kwargs(fields)},
+            is_elementwise=not isinstance(zone, str)
         )
-    
-    def lla_to_utm_zone_number(self) -> pl.Expr:
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def lla_to_utm_full(
+        self,
+        zone: Union[int, str, None] = None,
+        zone_exceptions: bool = True,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="lla_to_utm_full",
+            args=self._expr,
+            kwargs={**_utm_zone_kwargs(zone, zone_exceptions), **_fields_kwargs(fields)},
+            is_elementwise=not isinstance(zone, str)
+        )
+        return _finalize_output(expr, _UTM_FULL_FIELDS, output_fields, output_dtype)
+
+    def lla_to_utm_zone_number(
+        self,
+        zone: Optional[str] = None,
+        zone_exceptions: bool = True,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="lla_to_utm_zone_number",
             args=self._expr,
+            kwargs={**_utm_zone_kwargs(zone, zone_exceptions), **_fields_kwargs(fields)},
+            is_elementwise=zone is None
+        )
+
+    def utm_to_lla(
+        self,
+        zone_number: Union[int, pl.Expr],
+        hemisphere: Union[str, pl.Expr] = "north",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        if isinstance(zone_number, int):
+            if zone_number < 1 or zone_number > 60:
+                raise ValueError("`zone_number` parameter must be between 1 and 60!")
+            zone_number = pl.lit(zone_number, dtype=pl.UInt8)
+        if isinstance(hemisphere, str):
+            hemisphere = pl.lit(hemisphere)
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="utm_to_lla",
+            args=[self._expr, zone_number, hemisphere],
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
+        return _finalize_output(expr, _LLA_FIELDS, output_fields, output_dtype, output_format)
 
-    def rotate_map_coords(self, rotation: pl.Expr, scale: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def rotate_map_coords(
+        self,
+        rotation: pl.Expr,
+        scale: pl.Expr,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="rotate_map_coords",
             args=[self._expr, rotation, scale],
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
 
     
-    def interpolate_linear(self, other: pl.Expr, coef=0.5):
-        if coef < 0 or coef > 1:
-            raise ValueError("`coef` parameter must be between 0 and 1!")
+    def interpolate_linear(
+        self,
+        other: pl.Expr,
+        coef: Union[pl.Expr, float] = 0.5,
+        extrapolate: bool = False,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        if not isinstance(coef, pl.Expr):
+            if not extrapolate and (coef < 0 or coef > 1):
+                raise ValueError("`coef` parameter must be between 0 and 1!")
+            coef = pl.lit(coef, dtype=pl.Float64)
 
-        return register_plugin_function(
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="interpolate_linear",
-            args=[self._expr, other],
-            kwargs={"coef": coef},
+            args=[self._expr, other, coef],
+            kwargs={"extrapolate": extrapolate, **_fields_kwargs(fields)},
         )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
     
-    def quat_to_euler_angles(self) -> pl.Expr:
+    def interpolate_pose(
+        self,
+        other_pos: pl.Expr,
+        other_rot: pl.Expr,
+        t: Union[pl.Expr, float],
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        if not isinstance(t, pl.Expr):
+            t = pl.lit(t, dtype=pl.Float64)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="interpolate_pose",
+            args=[self._expr, other_pos, other_rot, t],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+
+    def interpolate_pose_at(
+        self,
+        timestamps: pl.Expr,
+        query: pl.Expr,
+        max_gap: Optional[Union[float, timedelta]] = None,
+        extrapolate: Literal["none", "nearest", "linear"] = "none",
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        if isinstance(max_gap, timedelta):
+            max_gap = max_gap / timedelta(microseconds=1)
         return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="interpolate_pose_at",
+            args=[self._expr, timestamps, query],
+            kwargs={"max_gap": max_gap, "extrapolate": extrapolate, **_fields_kwargs(fields)},
+            changes_length=True,
+        )
+
+    def quat_to_euler_angles(
+        self,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="quat_to_euler_angles",
             args=self._expr,
+            kwargs=_fields_kwargs(fields),
             is_elementwise=True
         )
+        return _finalize_output(expr, ["roll", "pitch", "yaw"], output_fields, output_dtype, output_format)
     
-    def get_rotation_matrix(self, offset: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def get_rotation_matrix(
+        self,
+        offset: pl.Expr,
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: Literal["list", "array"] = "list",
+    ) -> pl.Expr:
+        if output_format not in ("list", "array"):
+            raise ValueError("`output_format` parameter must be 'list' or 'array'!")
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
-            function_name="get_rotation_matrix",
+            function_name="get_rotation_matrix" if output_format == "list" else "get_rotation_matrix_array",
             args=[self._expr, offset],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        _check_output_dtype(output_dtype)
+        if output_dtype == pl.Float64:
+            return expr
+        return expr.cast(pl.List(output_dtype) if output_format == "list" else pl.Array(output_dtype, 16))
+
+    def euler_angles_to_quat(
+        self,
+        order: str = "XYZ",
+        convention: Literal["extrinsic", "intrinsic"] = "extrinsic",
+        degrees: bool = False,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="euler_angles_to_quat",
+            args=self._expr,
+            kwargs={
+                "order": order,
+                "convention": convention,
+                "degrees": degrees,
+                **_fields_kwargs(fields),
+            },
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def axis_angle_to_quat(
+        self,
+        degrees: bool = False,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="axis_angle_to_quat",
+            args=self._expr,
+            kwargs={"degrees": degrees, **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def quat_to_axis_angle(
+        self,
+        degrees: bool = False,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_to_axis_angle",
+            args=self._expr,
+            kwargs={"degrees": degrees, **_fields_kwargs(fields)},
+            is_elementwise=True
+        )
+        return _finalize_output(
+            expr, ["x", "y", "z", "angle"], output_fields, output_dtype, output_format
+        )
+
+    def rotation_matrix_to_quat(
+        self,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="rotation_matrix_to_quat",
+            args=self._expr,
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def quat_to_rotation_matrix(
+        self,
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: Literal["list", "array"] = "list",
+    ) -> pl.Expr:
+        if output_format not in ("list", "array"):
+            raise ValueError("`output_format` parameter must be 'list' or 'array'!")
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_to_rotation_matrix" if output_format == "list" else "quat_to_rotation_matrix_array",
+            args=self._expr,
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        _check_output_dtype(output_dtype)
+        if output_dtype == pl.Float64:
+            return expr
+        return expr.cast(pl.List(output_dtype) if output_format == "list" else pl.Array(output_dtype, 9))
+
+    def apply_matrix(
+        self,
+        matrix: Union[pl.Expr, Sequence[float]],
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        if not isinstance(matrix, pl.Expr):
+            matrix = pl.lit(pl.Series("matrix", [list(matrix)], dtype=pl.List(pl.Float64)))
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="apply_matrix",
+            args=[self._expr, matrix],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+    def matrix_to_quat_translation(self) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="matrix_to_quat_translation",
+            args=self._expr,
             is_elementwise=True
         )
 
+@pl.api.register_expr_namespace("quat")
+class QuatNameSpace:
+
+    def __init__(self, expr: pl.Expr):
+        self._expr = expr
+
+    def multiply(
+        self,
+        other: pl.Expr,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_multiply",
+            args=[self._expr, other],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def inverse(
+        self,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_inverse",
+            args=self._expr,
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def normalize(
+        self,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_normalize",
+            args=self._expr,
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def conjugate(
+        self,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_conjugate",
+            args=self._expr,
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def slerp(
+        self,
+        other: pl.Expr,
+        t: Union[pl.Expr, float],
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        if not isinstance(t, pl.Expr):
+            t = pl.lit(t, dtype=pl.Float64)
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_slerp",
+            args=[self._expr, other, t],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _QUAT_FIELDS, output_fields, output_dtype, output_format)
+
+    def angle_between(
+        self,
+        other: pl.Expr,
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_angle_between",
+            args=[self._expr, other],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _cast_output(expr, output_dtype)
+
+    def rotate_vector(
+        self,
+        vector: pl.Expr,
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="quat_rotate_vector",
+            args=[self._expr, vector],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+        return _finalize_output(expr, _XYZ_FIELDS, output_fields, output_dtype, output_format)
+
+
+@pl.api.register_expr_namespace("pose")
+class PoseNameSpace:
+
+    def __init__(self, expr: pl.Expr):
+        self._expr = expr
+
+    def compose(
+        self,
+        other: pl.Expr,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="pose_compose",
+            args=[self._expr, other],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+
+    def inverse(
+        self,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="pose_inverse",
+            args=self._expr,
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+
+    def relative_to(
+        self,
+        other: pl.Expr,
+        fields: FieldMapping = None,
+    ) -> pl.Expr:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="pose_relative_to",
+            args=[self._expr, other],
+            kwargs=_fields_kwargs(fields),
+            is_elementwise=True
+        )
+
+
 @pl.api.register_expr_namespace("distance")
 class DistanceNameSpace:
 
     def __init__(self, expr: pl.Expr):
         self._expr = expr
 
-    def euclidean_3d(self, other: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def euclidean_3d(
+        self,
+        other: pl.Expr,
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="euclidean_3d",
             args=[self._expr, other],
+            kwargs={"null_policy": null_policy, **_fields_kwargs(fields)},
             is_elementwise=True
 
         )
+        return _cast_output(expr, output_dtype)
     
 
-    def euclidean_2d(self, other: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def euclidean_2d(
+        self,
+        other: pl.Expr,
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="euclidean_2d",
             args=[self._expr, other],
+            kwargs={"null_policy": null_policy, **_fields_kwargs(fields)},
             is_elementwise=True
 
         )
+        return _cast_output(expr, output_dtype)
     
-    def cosine_similarity_2d(self, other: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def cosine_similarity_2d(
+        self,
+        other: pl.Expr,
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="cosine_similarity_2d",
             args=[self._expr, other],
+            kwargs={"null_policy": null_policy, **_fields_kwargs(fields)},
             is_elementwise=True
         )
+        return _cast_output(expr, output_dtype)
     
-    def cosine_similarity_3d(self, other: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def cosine_similarity_3d(
+        self,
+        other: pl.Expr,
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="cosine_similarity_3d",
             args=[self._expr, other],
+            kwargs={"null_policy": null_policy, **_fields_kwargs(fields)},
             is_elementwise=True
         )
+        return _cast_output(expr, output_dtype)
 
-    def bboxes_2d(self, other: pl.Expr) -> pl.Expr:
-        return register_plugin_function(
+    def haversine(
+        self,
+        other: pl.Expr,
+        radius: Optional[float] = None,
+        unit: str = "m",
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="haversine",
+            args=[self._expr, other],
+            kwargs={
+                "radius": radius,
+                "unit": unit,
+                "null_policy": null_policy,
+                **_fields_kwargs(fields),
+            },
+            is_elementwise=True
+        )
+        return _cast_output(expr, output_dtype)
+
+    def geodesic(
+        self,
+        other: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        unit: str = "m",
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="geodesic",
+            args=[self._expr, other],
+            kwargs={
+                **_ellipsoid_kwargs(ellipsoid),
+                "unit": unit,
+                "null_policy": null_policy,
+                **_fields_kwargs(fields),
+            },
+            is_elementwise=True
+        )
+        return _cast_output(expr, output_dtype)
+
+    def bearing(
+        self,
+        other: pl.Expr,
+        ellipsoid: Union[str, Dict[str, float]] = "WGS84",
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_fields: Optional[Dict[str, str]] = None,
+        output_dtype: PolarsDataType = pl.Float64,
+        output_format: OutputFormat = "struct",
+    ) -> pl.Expr:
+        expr = register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="bearing",
+            args=[self._expr, other],
+            kwargs={
+                **_ellipsoid_kwargs(ellipsoid),
+                "null_policy": null_policy,
+                **_fields_kwargs(fields),
+            },
+            is_elementwise=True
+        )
+        return _finalize_output(expr, ["initial", "final"], output_fields, output_dtype, output_format)
+
+    def bboxes_2d(
+        self,
+        other: pl.Expr,
+        null_policy: NullPolicy = "propagate",
+        fields: FieldMapping = None,
+        output_dtype: PolarsDataType = pl.Float64,
+    ) -> pl.Expr:
+        expr = register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="bboxes_2d",
             args=[self._expr, other],
+            kwargs={"null_policy": null_policy, **_fields_kwargs(fields)},
             is_elementwise=True
         )
+        return _cast_output(expr, output_dtype)
 
 
 class CoordTransformExpr(pl.Expr):
@@ -199,6 +1258,14 @@ class CoordTransformExpr(pl.Expr):
     def distance(self) -> DistanceNameSpace:
         return TransformNameSpace(self)
 
+    @property
+    def quat(self) -> QuatNameSpace:
+        return QuatNameSpace(self)
+
+    @property
+    def pose(self) -> PoseNameSpace:
+        return PoseNameSpace(self)
+
 
 class CTColumn(Protocol):
     def __cal__(
@@ -223,6 +1290,14 @@ class CTColumn(Protocol):
     def distance(self) -> DistanceNameSpace:
         ...
 
+    @property
+    def quat(self) -> QuatNameSpace:
+        ...
+
+    @property
+    def pose(self) -> PoseNameSpace:
+        ...
+
 
 col = cast(CTColumn, pl.col)
 
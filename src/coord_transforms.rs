@@ -1,9 +1,31 @@
 extern crate nalgebra as na;
 
-use map_3d::{deg2rad, ecef2geodetic, geodetic2ecef, rad2deg, Ellipsoid};
+use map_3d::{deg2rad, rad2deg};
 use na::{Quaternion, Rotation3, UnitQuaternion, Vector3, Vector4, Matrix4x3, Matrix3x4, Matrix1x4};
 use nalgebra::Const;
-use utm::{lat_lon_to_zone_number, to_utm_wgs84_no_zone};
+use utm::{lat_lon_to_zone_number, lat_to_zone_letter, to_utm_wgs84};
+
+use crate::ellipsoid::ReferenceEllipsoid;
+use geographiclib_rs::{DirectGeodesic, Geodesic};
+
+// Parameters of the ellipsoid and projection used by the `utm` crate.
+const UTM_SEMI_MAJOR_AXIS: f64 = 6378137.0;
+const UTM_FLATTENING: f64 = 1.0 / 298.257222101;
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500000.0;
+const UTM_FALSE_NORTHING: f64 = 10000000.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct UtmCoords {
+    pub easting: f64,
+    pub northing: f64,
+    pub alt: f64,
+    pub zone_number: u8,
+    pub zone_letter: Option<char>,
+    pub northern: bool,
+    pub convergence: f64,
+    pub scale_factor: f64,
+}
 
 pub fn rotation_from_quat(q: Vec<f64>) -> na::Rotation<f64, 3> {
     let quat =
@@ -26,6 +48,64 @@ pub fn get_rotation_matrix_elementwise(q: &Vec<f64>, t: &Vec<f64>) -> Vec<f64> {
 
 
 
+/// Splits a matrix laid out as by `get_rotation_matrix_elementwise` (16 values, or the
+/// first 12 of them) into its rotation and translation.
+fn split_transform_matrix(matrix: &[f64]) -> (na::Matrix3<f64>, Vector3<f64>) {
+    let rotation = na::Matrix3::from_fn(|row, col| matrix[4 * col + row]);
+    let translation = Vector3::new(matrix[3], matrix[7], matrix[11]);
+    (rotation, translation)
+}
+
+pub fn apply_matrix_elementwise(coords: Vec<f64>, matrix: &[f64]) -> (f64, f64, f64) {
+    let (rotation, translation) = split_transform_matrix(matrix);
+    let transformed = rotation * Vector3::from_vec(coords) + translation;
+    (transformed.x, transformed.y, transformed.z)
+}
+
+/// Quaternion `[x, y, z, w]` and offset `[x, y, z]` of a transform matrix.
+pub fn matrix_to_quat_translation_elementwise(matrix: &[f64]) -> ([f64; 4], [f64; 3]) {
+    let (rotation, translation) = split_transform_matrix(matrix);
+    let quat = UnitQuaternion::from_matrix(&rotation);
+    (
+        [quat.i, quat.j, quat.k, quat.w],
+        [translation.x, translation.y, translation.z],
+    )
+}
+
+/// Rigid transform `(rotation, offset)`: a quaternion `[x, y, z, w]` and an offset
+/// `[x, y, z]`, mapping points as `map_to_ecef_elementwise` does.
+pub type Pose = ([f64; 4], [f64; 3]);
+
+fn pose_to_isometry(pose: Pose) -> na::Isometry3<f64> {
+    let (rotation, offset) = pose;
+    let rotation =
+        UnitQuaternion::from_quaternion(Quaternion::from_vector(Vector4::from(rotation)));
+    na::Isometry3::from_parts(na::Translation3::from(Vector3::from(offset)), rotation)
+}
+
+fn isometry_to_pose(isometry: na::Isometry3<f64>) -> Pose {
+    let rotation = isometry.rotation;
+    let offset = isometry.translation.vector;
+    (
+        [rotation.i, rotation.j, rotation.k, rotation.w],
+        [offset.x, offset.y, offset.z],
+    )
+}
+
+/// Pose applying `other` first, then `pose`.
+pub fn pose_compose_elementwise(pose: Pose, other: Pose) -> Pose {
+    isometry_to_pose(pose_to_isometry(pose) * pose_to_isometry(other))
+}
+
+pub fn pose_inverse_elementwise(pose: Pose) -> Pose {
+    isometry_to_pose(pose_to_isometry(pose).inverse())
+}
+
+/// `pose` expressed in the frame of `other`.
+pub fn pose_relative_to_elementwise(pose: Pose, other: Pose) -> Pose {
+    isometry_to_pose(pose_to_isometry(other).inverse() * pose_to_isometry(pose))
+}
+
 pub fn map_to_ecef_elementwise(
     map_coords: Vec<f64>,
     rotation: Vec<f64>,
@@ -50,26 +130,367 @@ pub fn ecef_to_map_elementwise(
     (map_vector.x, map_vector.y, map_vector.z)
 }
 
-pub fn ecef_to_lla_elementwise(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
-    let lla = ecef2geodetic(x, y, z, Ellipsoid::WGS84);
+/// Geodetic latitude and longitude (in radians) and altitude of an ECEF point,
+/// using the closed-form solution of You (2000) as in `map_3d::ecef2geodetic`.
+fn ecef_to_geodetic(x: f64, y: f64, z: f64, ellipsoid: &ReferenceEllipsoid) -> (f64, f64, f64) {
+    let major = ellipsoid.semi_major_axis;
+    let minor = ellipsoid.semi_minor_axis();
+
+    let r = (x * x + y * y + z * z).sqrt();
+    let e = (major * major - minor * minor).sqrt();
+    let var = r * r - e * e;
+    let u = (0.5 * var + 0.5 * (var * var + 4.0 * e * e * z * z).sqrt()).sqrt();
+
+    let q = (x * x + y * y).sqrt();
+    let hu_e = (u * u + e * e).sqrt();
+    let mut beta = (hu_e / u * z / q).atan();
+
+    let eps = ((minor * u - major * hu_e + e * e) * beta.sin())
+        / (major * hu_e / beta.cos() - e * e * beta.cos());
+    beta += eps;
+
+    let lat = (major / minor * beta.tan()).atan();
+    let lon = y.atan2(x);
+
+    let v1 = z - minor * beta.sin();
+    let v2 = q - major * beta.cos();
+    let inside = (x * x / major / major) + (y * y / major / major) + (z * z / minor / minor) < 1.0;
+    let alt = if inside {
+        -(v1 * v1 + v2 * v2).sqrt()
+    } else {
+        (v1 * v1 + v2 * v2).sqrt()
+    };
+
+    (lat, lon, alt)
+}
+
+/// ECEF coordinates of a point with geodetic latitude and longitude given in radians.
+fn geodetic_to_ecef(
+    lat: f64,
+    lon: f64,
+    alt: f64,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let n = ellipsoid.radius_normal(lat);
+    let axes_ratio = ellipsoid.semi_minor_axis() / ellipsoid.semi_major_axis;
+
+    let x = (n + alt) * lat.cos() * lon.cos();
+    let y = (n + alt) * lat.cos() * lon.sin();
+    let z = (n * axes_ratio * axes_ratio + alt) * lat.sin();
+    (x, y, z)
+}
+
+pub fn ecef_to_lla_elementwise(
+    x: f64,
+    y: f64,
+    z: f64,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let lla = ecef_to_geodetic(x, y, z, ellipsoid);
     (rad2deg(lla.1), rad2deg(lla.0), lla.2)
 }
 
-pub fn lla_to_ecef_elementwise(lon: f64, lat: f64, alt: f64) -> (f64, f64, f64) {
-    let (x, y, z) = geodetic2ecef(deg2rad(lat), deg2rad(lon), alt, Ellipsoid::WGS84);
+pub fn lla_to_ecef_elementwise(
+    lon: f64,
+    lat: f64,
+    alt: f64,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (x, y, z) = geodetic_to_ecef(deg2rad(lat), deg2rad(lon), alt, ellipsoid);
     (x, y, z)
 }
 
+/// Rotates an ECEF vector into the East-North-Up frame at a latitude and longitude given in radians.
+fn ecef_vector_to_enu(vector: Vector3<f64>, lat0: f64, lon0: f64) -> Vector3<f64> {
+    let t = lon0.cos() * vector.x + lon0.sin() * vector.y;
+    Vector3::new(
+        -lon0.sin() * vector.x + lon0.cos() * vector.y,
+        -lat0.sin() * t + lat0.cos() * vector.z,
+        lat0.cos() * t + lat0.sin() * vector.z,
+    )
+}
+
+/// Rotates an East-North-Up vector at a latitude and longitude given in radians into the ECEF frame.
+fn enu_vector_to_ecef(vector: Vector3<f64>, lat0: f64, lon0: f64) -> Vector3<f64> {
+    let t = lat0.cos() * vector.z - lat0.sin() * vector.y;
+    Vector3::new(
+        lon0.cos() * t - lon0.sin() * vector.x,
+        lon0.sin() * t + lon0.cos() * vector.x,
+        lat0.sin() * vector.z + lat0.cos() * vector.y,
+    )
+}
+
+pub fn ecef_to_enu_elementwise(
+    ecef_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (lat0, lon0) = (deg2rad(origin[1]), deg2rad(origin[0]));
+    let (x0, y0, z0) = geodetic_to_ecef(lat0, lon0, origin[2], ellipsoid);
+    let enu = ecef_vector_to_enu(
+        Vector3::from_vec(ecef_coords) - Vector3::new(x0, y0, z0),
+        lat0,
+        lon0,
+    );
+    (enu.x, enu.y, enu.z)
+}
+
+pub fn enu_to_ecef_elementwise(
+    enu_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (lat0, lon0) = (deg2rad(origin[1]), deg2rad(origin[0]));
+    let (x0, y0, z0) = geodetic_to_ecef(lat0, lon0, origin[2], ellipsoid);
+    let ecef =
+        enu_vector_to_ecef(Vector3::from_vec(enu_coords), lat0, lon0) + Vector3::new(x0, y0, z0);
+    (ecef.x, ecef.y, ecef.z)
+}
+
+pub fn lla_to_enu_elementwise(
+    lla_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (x, y, z) = lla_to_ecef_elementwise(lla_coords[0], lla_coords[1], lla_coords[2], ellipsoid);
+    ecef_to_enu_elementwise(vec![x, y, z], origin, ellipsoid)
+}
+
+pub fn enu_to_lla_elementwise(
+    enu_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (x, y, z) = enu_to_ecef_elementwise(enu_coords, origin, ellipsoid);
+    ecef_to_lla_elementwise(x, y, z, ellipsoid)
+}
+
+pub fn ecef_to_ned_elementwise(
+    ecef_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (e, n, u) = ecef_to_enu_elementwise(ecef_coords, origin, ellipsoid);
+    (n, e, -u)
+}
+
+pub fn ned_to_ecef_elementwise(
+    ned_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let enu_coords = vec![ned_coords[1], ned_coords[0], -ned_coords[2]];
+    enu_to_ecef_elementwise(enu_coords, origin, ellipsoid)
+}
+
+pub fn lla_to_ned_elementwise(
+    lla_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (e, n, u) = lla_to_enu_elementwise(lla_coords, origin, ellipsoid);
+    (n, e, -u)
+}
+
+pub fn ned_to_lla_elementwise(
+    ned_coords: Vec<f64>,
+    origin: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let enu_coords = vec![ned_coords[1], ned_coords[0], -ned_coords[2]];
+    enu_to_lla_elementwise(enu_coords, origin, ellipsoid)
+}
+
+/// Azimuth (clockwise from north, in degrees), elevation (in degrees) and slant range
+/// of a target LLA point as seen from an observer LLA point.
+pub fn lla_to_aer_elementwise(
+    lla_coords: Vec<f64>,
+    observer: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (e, n, u) = lla_to_enu_elementwise(lla_coords, observer, ellipsoid);
+    let horizontal_range = e.hypot(n);
+    let azimuth = rad2deg(e.atan2(n)).rem_euclid(360.0);
+    let elevation = rad2deg(u.atan2(horizontal_range));
+    let range = horizontal_range.hypot(u);
+    (azimuth, elevation, range)
+}
+
+pub fn aer_to_lla_elementwise(
+    aer_coords: Vec<f64>,
+    observer: Vec<f64>,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    let (azimuth, elevation, range) = (
+        deg2rad(aer_coords[0]),
+        deg2rad(aer_coords[1]),
+        aer_coords[2],
+    );
+    let horizontal_range = range * elevation.cos();
+    let enu_coords = vec![
+        horizontal_range * azimuth.sin(),
+        horizontal_range * azimuth.cos(),
+        range * elevation.sin(),
+    ];
+    enu_to_lla_elementwise(enu_coords, observer, ellipsoid)
+}
+
+/// Moves a LLA point along a geodesic with a given initial bearing (in degrees
+/// clockwise from north) by a given distance (in metres); altitude is preserved.
+pub fn geodesic_destination_elementwise(
+    lon: f64,
+    lat: f64,
+    alt: f64,
+    bearing: f64,
+    distance: f64,
+    geodesic: &Geodesic,
+) -> (f64, f64, f64) {
+    let (lat_new, lon_new): (f64, f64) = geodesic.direct(lat, lon, bearing, distance);
+    (lon_new, lat_new, alt)
+}
+
 pub fn lla_to_utm_zone_number_elementwise(lon: f64, lat: f64) -> u8 {
     let zone_number = lat_lon_to_zone_number(lat, lon);
     zone_number
 }
 
-pub fn lla_to_utm_elementwise(lon: f64, lat: f64, alt: f64) -> (f64, f64, f64) {
-    let (northing, easting, _meridian_convergence) = to_utm_wgs84_no_zone(lat, lon);
+/// UTM zone number derived from longitude only, ignoring the Norway/Svalbard exceptions.
+pub fn lla_to_utm_zone_number_regular_elementwise(lon: f64) -> u8 {
+    let zone_index = ((lon + 180.0) / 6.0).floor().rem_euclid(60.0);
+    zone_index as u8 + 1
+}
+
+pub fn lla_to_utm_elementwise(lon: f64, lat: f64, alt: f64, zone_number: u8) -> (f64, f64, f64) {
+    let (northing, easting, _meridian_convergence) = to_utm_wgs84(lat, lon, zone_number);
     (easting, northing, alt)
 }
 
+/// Meridian convergence (in degrees) and point scale factor of the UTM projection.
+///
+/// Convergence is the clockwise angle from true north to grid north,
+/// i.e. `grid bearing = true bearing - convergence`.
+pub fn utm_convergence_and_scale_elementwise(lon: f64, lat: f64, zone_number: u8) -> (f64, f64) {
+    let e2 = 2.0 * UTM_FLATTENING - UTM_FLATTENING * UTM_FLATTENING;
+    let ep2 = e2 / (1.0 - e2);
+
+    let lat = deg2rad(lat);
+    let d_lon = deg2rad(lon - (zone_number as f64 * 6.0 - 183.0));
+    let eta2 = ep2 * lat.cos().powi(2);
+    let t2 = lat.tan().powi(2);
+    let l2 = (d_lon * lat.cos()).powi(2);
+
+    let convergence = d_lon
+        * lat.sin()
+        * (1.0
+            + l2 / 3.0 * (1.0 + 3.0 * eta2 + 2.0 * eta2.powi(2))
+            + l2.powi(2) / 15.0 * (2.0 - t2));
+    let scale_factor = UTM_SCALE_FACTOR
+        * (1.0
+            + l2 / 2.0 * (1.0 + eta2)
+            + l2.powi(2) / 24.0 * (5.0 - 4.0 * t2 + 14.0 * eta2 - 28.0 * t2 * eta2));
+
+    (rad2deg(convergence), scale_factor)
+}
+
+pub fn lla_to_utm_full_elementwise(lon: f64, lat: f64, alt: f64, zone_number: u8) -> UtmCoords {
+    let (northing, easting, _meridian_convergence) = to_utm_wgs84(lat, lon, zone_number);
+    // The equator belongs to band N, but `to_utm_wgs84` adds the false northing there.
+    let northern = lat >= 0.0;
+    let northing = if lat == 0.0 {
+        northing - UTM_FALSE_NORTHING
+    } else {
+        northing
+    };
+    let (convergence, scale_factor) = utm_convergence_and_scale_elementwise(lon, lat, zone_number);
+    UtmCoords {
+        easting,
+        northing,
+        alt,
+        zone_number,
+        zone_letter: lat_to_zone_letter(lat),
+        northern,
+        convergence,
+        scale_factor,
+    }
+}
+
+/// Inverse of `lla_to_utm_elementwise` for a given hemisphere.
+///
+/// An initial guess is taken from the inverse series of Snyder (USGS PP 1395)
+/// and then refined against the forward projection, so that the result
+/// round-trips with `lla_to_utm_elementwise` well below a millimetre.
+pub fn utm_to_lla_elementwise(
+    easting: f64,
+    northing: f64,
+    alt: f64,
+    zone_number: u8,
+    northern: bool,
+) -> (f64, f64, f64) {
+    let a = UTM_SEMI_MAJOR_AXIS;
+    let k0 = UTM_SCALE_FACTOR;
+    let e2 = 2.0 * UTM_FLATTENING - UTM_FLATTENING * UTM_FLATTENING;
+    let ep2 = e2 / (1.0 - e2);
+
+    let x = easting - UTM_FALSE_EASTING;
+    let y = if northern {
+        northing
+    } else {
+        northing - UTM_FALSE_NORTHING
+    };
+    let lon_origin = deg2rad(zone_number as f64 * 6.0 - 183.0);
+
+    let mu = y / k0 / (a * (1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0));
+    let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+    let phi1 = mu
+        + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+        + (21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+        + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+        + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+    let c1 = ep2 * phi1.cos().powi(2);
+    let t1 = phi1.tan().powi(2);
+    let n1 = a / (1.0 - e2 * phi1.sin().powi(2)).sqrt();
+    let r1 = a * (1.0 - e2) / (1.0 - e2 * phi1.sin().powi(2)).powf(1.5);
+    let d = x / (n1 * k0);
+
+    let mut lat = phi1
+        - (n1 * phi1.tan() / r1)
+            * (d.powi(2) / 2.0
+                - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1.powi(2) - 9.0 * ep2) * d.powi(4) / 24.0
+                + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1.powi(2)
+                    - 252.0 * ep2
+                    - 3.0 * c1.powi(2))
+                    * d.powi(6)
+                    / 720.0);
+    let mut lon = lon_origin
+        + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+            + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1.powi(2) + 8.0 * ep2 + 24.0 * t1.powi(2))
+                * d.powi(5)
+                / 120.0)
+            / phi1.cos();
+
+    for _ in 0..5 {
+        let (northing_fwd, easting_fwd, _) = to_utm_wgs84(rad2deg(lat), rad2deg(lon), zone_number);
+        let northing_fwd = if lat > 0.0 {
+            northing_fwd
+        } else {
+            northing_fwd - UTM_FALSE_NORTHING
+        };
+        let d_northing = y - northing_fwd;
+        let d_easting = x - (easting_fwd - UTM_FALSE_EASTING);
+        if d_northing.abs() < 1.0e-9 && d_easting.abs() < 1.0e-9 {
+            break;
+        }
+        let sin_lat2 = lat.sin().powi(2);
+        let radius_meridian = a * (1.0 - e2) / (1.0 - e2 * sin_lat2).powf(1.5);
+        let radius_normal = a / (1.0 - e2 * sin_lat2).sqrt();
+        lat += d_northing / (k0 * radius_meridian);
+        lon += d_easting / (k0 * radius_normal * lat.cos());
+    }
+
+    (rad2deg(lon), rad2deg(lat), alt)
+}
+
 pub fn rotate_map_coords_elementwise(
     map_coords: Vec<f64>,
     rotation: Vec<f64>,
@@ -95,6 +516,77 @@ pub fn interpolate_linear_elementwise(
     (interpolated.x, interpolated.y, interpolated.z)
 }
 
+/// What pose resampling returns for query times outside the sampled range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extrapolation {
+    None,
+    Nearest,
+    Linear,
+}
+
+impl Extrapolation {
+    pub fn from_name(name: &str) -> Option<Extrapolation> {
+        match name {
+            "none" => Some(Extrapolation::None),
+            "nearest" => Some(Extrapolation::Nearest),
+            "linear" => Some(Extrapolation::Linear),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the samples to interpolate between for `query` in ascending `times`: the
+/// indices of both samples and the fraction of the way from the first to the second.
+/// `None` when the query is out of range without extrapolation or further than
+/// `max_gap` from the samples.
+pub fn bracket_samples(
+    times: &[f64],
+    query: f64,
+    max_gap: Option<f64>,
+    extrapolation: Extrapolation,
+) -> Option<(usize, usize, f64)> {
+    let last = times.len().checked_sub(1)?;
+    let within_gap = |gap: f64| max_gap.is_none_or(|max_gap| gap <= max_gap);
+    let fraction = |i0: usize, i1: usize| {
+        let span = times[i1] - times[i0];
+        if span > 0.0 {
+            (query - times[i0]) / span
+        } else {
+            0.0
+        }
+    };
+
+    let (i0, i1) = match times.partition_point(|&time| time <= query) {
+        0 | 1 if query == times[0] => return Some((0, 0, 0.0)),
+        0 => {
+            if !within_gap(times[0] - query) {
+                return None;
+            }
+            match extrapolation {
+                Extrapolation::None => return None,
+                Extrapolation::Nearest => return Some((0, 0, 0.0)),
+                Extrapolation::Linear => (0, last.min(1)),
+            }
+        }
+        i if i > last => {
+            if !within_gap(query - times[last]) {
+                return None;
+            }
+            match extrapolation {
+                _ if query == times[last] => return Some((last, last, 0.0)),
+                Extrapolation::None => return None,
+                Extrapolation::Nearest => return Some((last, last, 0.0)),
+                Extrapolation::Linear => (last.saturating_sub(1), last),
+            }
+        }
+        i => (i - 1, i),
+    };
+    if !within_gap(times[i1] - times[i0]) {
+        return None;
+    }
+    Some((i0, i1, fraction(i0, i1)))
+}
+
 pub fn quat_to_euler_angles_elementwise(quat: Vec<f64>) -> (f64, f64, f64) {
     let quat: na::Unit<Quaternion<f64>> =
         UnitQuaternion::from_quaternion(Quaternion::from_vector(Vector4::from_vec(quat)));
@@ -103,9 +595,20 @@ pub fn quat_to_euler_angles_elementwise(quat: Vec<f64>) -> (f64, f64, f64) {
 
 #[cfg(test)]
 mod transform_tests {
-    use crate::coord_transforms::{ecef_to_lla_elementwise, map_to_ecef_elementwise};
+    use crate::coord_transforms::{
+        aer_to_lla_elementwise, apply_matrix_elementwise, bracket_samples, ecef_to_enu_elementwise,
+        ecef_to_lla_elementwise, enu_to_lla_elementwise, geodesic_destination_elementwise,
+        get_rotation_matrix_elementwise, lla_to_aer_elementwise, lla_to_ecef_elementwise,
+        lla_to_enu_elementwise, lla_to_ned_elementwise, lla_to_utm_elementwise,
+        lla_to_utm_full_elementwise, lla_to_utm_zone_number_elementwise,
+        lla_to_utm_zone_number_regular_elementwise, map_to_ecef_elementwise,
+        matrix_to_quat_translation_elementwise, ned_to_ecef_elementwise, pose_compose_elementwise,
+        pose_inverse_elementwise, pose_relative_to_elementwise, utm_to_lla_elementwise,
+        Extrapolation,
+    };
 
     use super::quat_to_euler_angles_elementwise;
+    use crate::ellipsoid::ReferenceEllipsoid;
 
     #[test]
     fn test_map_to_ecef() {
@@ -130,7 +633,12 @@ mod transform_tests {
             (36.077147686805766, 56.783927007002845, 165.8986865637805);
 
         assert_eq!(
-            ecef_to_lla_elementwise(ecef_coords.0, ecef_coords.1, ecef_coords.2),
+            ecef_to_lla_elementwise(
+                ecef_coords.0,
+                ecef_coords.1,
+                ecef_coords.2,
+                &ReferenceEllipsoid::WGS84
+            ),
             expected_result
         )
     }
@@ -147,4 +655,255 @@ mod transform_tests {
         let euler_angles = quat_to_euler_angles_elementwise(quat);
         assert!(euler_angles.2.abs() - expected_yaw.abs() < 1.0e-9)
     }
+
+    #[test]
+    fn test_utm_circular_transformation() {
+        let points: Vec<(f64, f64, f64)> = vec![
+            (36.077147686805766, 56.783927007002845, 165.8986865637805),
+            (18.42406, -33.92487, 12.0),
+            (-123.45, 40.62, -3.5),
+            (10.5, 60.1, 0.0),
+        ];
+
+        for (lon, lat, alt) in points {
+            let zone_number = lla_to_utm_zone_number_elementwise(lon, lat);
+            let (easting, northing, _) = lla_to_utm_elementwise(lon, lat, alt, zone_number);
+            let (lon_new, lat_new, alt_new) =
+                utm_to_lla_elementwise(easting, northing, alt, zone_number, lat >= 0.0);
+            let (easting_new, northing_new, _) =
+                lla_to_utm_elementwise(lon_new, lat_new, alt_new, zone_number);
+
+            assert!((easting_new - easting).abs() < 1.0e-3);
+            assert!((northing_new - northing).abs() < 1.0e-3);
+            assert!((lon_new - lon).abs() < 1.0e-8);
+            assert!((lat_new - lat).abs() < 1.0e-8);
+            assert_eq!(alt_new, alt);
+        }
+    }
+
+    #[test]
+    fn test_lla_to_utm_full() {
+        let (lon, lat, alt) = (37.732, 55.820, 163.916);
+        let utm_full = lla_to_utm_full_elementwise(lon, lat, alt, 37);
+
+        assert_eq!(
+            (utm_full.easting, utm_full.northing, utm_full.alt),
+            lla_to_utm_elementwise(lon, lat, alt, 37)
+        );
+        assert_eq!(utm_full.zone_number, 37);
+        assert_eq!(utm_full.zone_letter, Some('U'));
+        assert!(utm_full.northern);
+        // west of the central meridian of zone 37 (39E) grid north points west of true north
+        assert!((utm_full.convergence - (-1.049041)).abs() < 1.0e-6);
+        assert!((utm_full.scale_factor - 0.999677).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_utm_fixed_zone() {
+        // Bergen lies in zone 32V because of the Norway exception
+        let (lon, lat, alt) = (5.32, 60.39, 10.0);
+        assert_eq!(lla_to_utm_zone_number_elementwise(lon, lat), 32);
+        assert_eq!(lla_to_utm_zone_number_regular_elementwise(lon), 31);
+
+        // projecting into a neighbouring zone keeps the track continuous
+        let (easting_31, northing_31, _) = lla_to_utm_elementwise(lon, lat, alt, 31);
+        let (easting_32, _, _) = lla_to_utm_elementwise(lon, lat, alt, 32);
+        assert!(easting_31 > easting_32 + 300000.0);
+        let (lon_new, lat_new, _) = utm_to_lla_elementwise(easting_31, northing_31, alt, 31, true);
+        assert!((lon_new - lon).abs() < 1.0e-8);
+        assert!((lat_new - lat).abs() < 1.0e-8);
+
+        assert_eq!(lla_to_utm_zone_number_regular_elementwise(-180.0), 1);
+        assert_eq!(lla_to_utm_zone_number_regular_elementwise(179.9), 60);
+        assert_eq!(lla_to_utm_zone_number_regular_elementwise(180.0), 1);
+    }
+
+    #[test]
+    fn test_lla_to_ecef_ellipsoids() {
+        for name in ["wgs84", "grs80", "pz90", "krassovsky", "bessel"] {
+            let ellipsoid = ReferenceEllipsoid::from_name(name).unwrap();
+            let a = ellipsoid.semi_major_axis;
+            let b = ellipsoid.semi_minor_axis();
+
+            let equator = lla_to_ecef_elementwise(90.0, 0.0, 100.0, &ellipsoid);
+            assert!(equator.0.abs() < 1.0e-6);
+            assert!((equator.1 - (a + 100.0)).abs() < 1.0e-6);
+            assert!(equator.2.abs() < 1.0e-6);
+
+            let pole = lla_to_ecef_elementwise(0.0, 90.0, 0.0, &ellipsoid);
+            assert!((pole.2 - b).abs() < 1.0e-6);
+
+            let (lon, lat, alt) = (36.077147686805766, 56.783927007002845, 165.8986865637805);
+            let (x, y, z) = lla_to_ecef_elementwise(lon, lat, alt, &ellipsoid);
+            let (lon_new, lat_new, alt_new) = ecef_to_lla_elementwise(x, y, z, &ellipsoid);
+            assert!((lon_new - lon).abs() < 1.0e-9);
+            assert!((lat_new - lat).abs() < 1.0e-9);
+            assert!((alt_new - alt).abs() < 1.0e-4);
+        }
+
+        // SK-42 and WGS84 heights of the same ECEF point differ by tens of metres
+        let (x, y, z) = (2830593.6327610738, 2062375.5703225536, 5312896.0721501345);
+        let krassovsky = ReferenceEllipsoid::from_name("krassovsky").unwrap();
+        let (_, _, alt_wgs84) = ecef_to_lla_elementwise(x, y, z, &ReferenceEllipsoid::WGS84);
+        let (_, _, alt_sk42) = ecef_to_lla_elementwise(x, y, z, &krassovsky);
+        assert!((alt_wgs84 - alt_sk42).abs() > 10.0);
+    }
+
+    #[test]
+    fn test_enu_ned() {
+        let wgs84 = ReferenceEllipsoid::WGS84;
+        let origin: Vec<f64> = vec![37.732, 55.820, 150.0];
+
+        // a point 100 metres above the origin is straight up in ENU and straight down in NED
+        let above = lla_to_enu_elementwise(vec![37.732, 55.820, 250.0], origin.clone(), &wgs84);
+        assert!(above.0.abs() < 1.0e-6 && above.1.abs() < 1.0e-6);
+        assert!((above.2 - 100.0).abs() < 1.0e-6);
+        let above_ned = lla_to_ned_elementwise(vec![37.732, 55.820, 250.0], origin.clone(), &wgs84);
+        assert!((above_ned.2 + 100.0).abs() < 1.0e-6);
+
+        // north and east of the origin
+        let north = lla_to_enu_elementwise(vec![37.732, 55.821, 150.0], origin.clone(), &wgs84);
+        assert!(north.0.abs() < 1.0e-6 && north.1 > 111.0 && north.1 < 112.0);
+        let east = lla_to_enu_elementwise(vec![37.733, 55.820, 150.0], origin.clone(), &wgs84);
+        assert!(east.0 > 62.0 && east.0 < 63.0 && east.1.abs() < 1.0e-3);
+
+        let lla = (37.9, 55.7, 180.0);
+        let (x, y, z) = lla_to_ecef_elementwise(lla.0, lla.1, lla.2, &wgs84);
+        let enu = ecef_to_enu_elementwise(vec![x, y, z], origin.clone(), &wgs84);
+        let lla_new = enu_to_lla_elementwise(vec![enu.0, enu.1, enu.2], origin.clone(), &wgs84);
+        assert!((lla_new.0 - lla.0).abs() < 1.0e-9);
+        assert!((lla_new.1 - lla.1).abs() < 1.0e-9);
+        assert!((lla_new.2 - lla.2).abs() < 1.0e-4);
+
+        let ned = lla_to_ned_elementwise(vec![lla.0, lla.1, lla.2], origin.clone(), &wgs84);
+        let ecef_new = ned_to_ecef_elementwise(vec![ned.0, ned.1, ned.2], origin, &wgs84);
+        assert!((ecef_new.0 - x).abs() < 1.0e-6);
+        assert!((ecef_new.1 - y).abs() < 1.0e-6);
+        assert!((ecef_new.2 - z).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_aer() {
+        let wgs84 = ReferenceEllipsoid::WGS84;
+        let observer: Vec<f64> = vec![37.732, 55.820, 150.0];
+
+        let zenith = lla_to_aer_elementwise(vec![37.732, 55.820, 1150.0], observer.clone(), &wgs84);
+        assert!((zenith.1 - 90.0).abs() < 1.0e-6);
+        assert!((zenith.2 - 1000.0).abs() < 1.0e-6);
+
+        let east = lla_to_aer_elementwise(vec![37.742, 55.820, 150.0], observer.clone(), &wgs84);
+        assert!((east.0 - 90.0).abs() < 0.01);
+        let west = lla_to_aer_elementwise(vec![37.722, 55.820, 150.0], observer.clone(), &wgs84);
+        assert!((west.0 - 270.0).abs() < 0.01);
+        assert!(west.1 < 0.0);
+
+        let target: Vec<f64> = vec![37.9, 55.7, 2500.0];
+        let (azimuth, elevation, range) =
+            lla_to_aer_elementwise(target.clone(), observer.clone(), &wgs84);
+        let (lon, lat, alt) =
+            aer_to_lla_elementwise(vec![azimuth, elevation, range], observer, &wgs84);
+        assert!((lon - target[0]).abs() < 1.0e-9);
+        assert!((lat - target[1]).abs() < 1.0e-9);
+        assert!((alt - target[2]).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_geodesic_destination() {
+        // reference values computed with GeographicLib
+        let wgs84 = geographiclib_rs::Geodesic::wgs84();
+        let (lon, lat, alt) =
+            geodesic_destination_elementwise(-73.78, 40.64, 12.0, 45.0, 10.0e6, &wgs84);
+
+        assert!((lon - 49.052487092959836).abs() < 1.0e-9);
+        assert!((lat - 32.621100463725796).abs() < 1.0e-9);
+        assert_eq!(alt, 12.0);
+    }
+
+    #[test]
+    fn test_apply_matrix() {
+        let rotation = vec![0.1, -0.3, 0.5, 0.8];
+        let offset = vec![2800000.0, 2200000.0, 5200000.0];
+        let map_coords = vec![12.5, -40.0, 3.0];
+        let matrix = get_rotation_matrix_elementwise(&rotation, &offset);
+
+        let (x, y, z) = apply_matrix_elementwise(map_coords.clone(), &matrix);
+        let (x_exp, y_exp, z_exp) =
+            map_to_ecef_elementwise(map_coords, rotation.clone(), offset.clone());
+        assert!((x - x_exp).abs() < 1e-6);
+        assert!((y - y_exp).abs() < 1e-6);
+        assert!((z - z_exp).abs() < 1e-6);
+
+        let (quat, translation) = matrix_to_quat_translation_elementwise(&matrix[..12]);
+        let norm = rotation.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let sign = quat[3].signum();
+        for (value, expected) in quat.iter().zip(&rotation) {
+            assert!((sign * value - expected / norm).abs() < 1e-9);
+        }
+        assert_eq!(translation.to_vec(), offset);
+    }
+
+    #[test]
+    fn test_bracket_samples() {
+        let times = [0.0, 10.0, 20.0, 50.0];
+        let none = Extrapolation::None;
+        assert_eq!(bracket_samples(&times, 15.0, None, none), Some((1, 2, 0.5)));
+        assert_eq!(bracket_samples(&times, 0.0, None, none), Some((0, 0, 0.0)));
+        assert_eq!(bracket_samples(&times, 50.0, None, none), Some((3, 3, 0.0)));
+        assert_eq!(bracket_samples(&times, 30.0, Some(20.0), none), None);
+        assert_eq!(bracket_samples(&times, 60.0, None, none), None);
+
+        let nearest = Extrapolation::Nearest;
+        assert_eq!(
+            bracket_samples(&times, -5.0, None, nearest),
+            Some((0, 0, 0.0))
+        );
+        assert_eq!(bracket_samples(&times, 60.0, Some(5.0), nearest), None);
+        let linear = Extrapolation::Linear;
+        assert_eq!(
+            bracket_samples(&times, 65.0, None, linear),
+            Some((2, 3, 1.5))
+        );
+        assert_eq!(
+            bracket_samples(&times, -5.0, None, linear),
+            Some((0, 1, -0.5))
+        );
+        assert_eq!(bracket_samples(&[], 1.0, None, linear), None);
+    }
+
+    #[test]
+    fn test_pose_algebra() {
+        let sensor_to_vehicle = ([0.0, 0.0, 0.6, 0.8], [1.5, 0.0, 2.0]);
+        let vehicle_to_map = ([0.1, -0.3, 0.5, 0.8], [100.0, -20.0, 3.0]);
+        let point = vec![4.0, -1.0, 0.5];
+
+        let sensor_to_map = pose_compose_elementwise(vehicle_to_map, sensor_to_vehicle);
+        let (x, y, z) = map_to_ecef_elementwise(
+            point.clone(),
+            sensor_to_map.0.to_vec(),
+            sensor_to_map.1.to_vec(),
+        );
+        let (vx, vy, vz) = map_to_ecef_elementwise(
+            point,
+            sensor_to_vehicle.0.to_vec(),
+            sensor_to_vehicle.1.to_vec(),
+        );
+        let expected = map_to_ecef_elementwise(
+            vec![vx, vy, vz],
+            vehicle_to_map.0.to_vec(),
+            vehicle_to_map.1.to_vec(),
+        );
+        assert!((x - expected.0).abs() < 1e-9);
+        assert!((y - expected.1).abs() < 1e-9);
+        assert!((z - expected.2).abs() < 1e-9);
+
+        let relative = pose_relative_to_elementwise(sensor_to_map, vehicle_to_map);
+        for (value, expected) in relative.1.iter().zip(sensor_to_vehicle.1) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+
+        let identity =
+            pose_compose_elementwise(vehicle_to_map, pose_inverse_elementwise(vehicle_to_map));
+        assert!(identity.1.iter().all(|value| value.abs() < 1e-9));
+        assert!((identity.0[3].abs() - 1.0).abs() < 1e-12);
+    }
 }
@@ -1,3 +1,47 @@
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+
+/// Mean Earth radius (IUGG), in metres.
+pub const EARTH_MEAN_RADIUS: f64 = 6371008.8;
+
+/// What a distance kernel returns when one of its input coordinates is null.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NullPolicy {
+    Propagate,
+    Zero,
+    Raise,
+}
+
+impl NullPolicy {
+    pub fn from_name(name: &str) -> Option<NullPolicy> {
+        match name {
+            "propagate" => Some(NullPolicy::Propagate),
+            "zero" => Some(NullPolicy::Zero),
+            "raise" => Some(NullPolicy::Raise),
+            _ => None,
+        }
+    }
+
+    /// Applies a kernel to coordinates that may be missing. `NullPolicy::Zero` gives
+    /// the default (zero) result and with `NullPolicy::Raise` a null yields `Err`
+    /// holding the position of the first missing coordinate.
+    pub fn apply<const N: usize, T: Default>(
+        &self,
+        coords: [Option<f64>; N],
+        func_elementwise: impl Fn([f64; N]) -> T,
+    ) -> Result<Option<T>, usize> {
+        match coords.iter().position(|c| c.is_none()) {
+            None => Ok(Some(func_elementwise(
+                coords.map(|c| c.unwrap_or_default()),
+            ))),
+            Some(position) => match self {
+                NullPolicy::Propagate => Ok(None),
+                NullPolicy::Zero => Ok(Some(T::default())),
+                NullPolicy::Raise => Err(position),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PointCoords {
     pub x: f64,
@@ -45,6 +89,53 @@ pub fn cosine_similarity_3d_elementwise(
     res
 }
 
+/// Initial and final azimuths (in degrees clockwise from north, within [0, 360))
+/// of the shortest geodesic from the first point to the second one.
+pub fn geodesic_bearing_elementwise(
+    lon1: f64,
+    lat1: f64,
+    lon2: f64,
+    lat2: f64,
+    geodesic: &Geodesic,
+) -> (f64, f64) {
+    let (azimuth1, azimuth2, _arc_length): (f64, f64, f64) =
+        geodesic.inverse(lat1, lon1, lat2, lon2);
+    (azimuth1.rem_euclid(360.0), azimuth2.rem_euclid(360.0))
+}
+
+/// Number of metres in a distance unit.
+pub fn meters_per_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "m" => Some(1.0),
+        "km" => Some(1000.0),
+        "mi" => Some(1609.344),
+        "nmi" => Some(1852.0),
+        "ft" => Some(0.3048),
+        _ => None,
+    }
+}
+
+/// Great-circle distance (in units of `radius`) between two points on a sphere.
+pub fn haversine_elementwise(lon1: f64, lat1: f64, lon2: f64, lat2: f64, radius: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * radius * a.sqrt().min(1.0).asin()
+}
+
+/// Length (in metres) of the shortest geodesic between two points on an ellipsoid (Karney, 2013).
+pub fn geodesic_elementwise(
+    lon1: f64,
+    lat1: f64,
+    lon2: f64,
+    lat2: f64,
+    geodesic: &Geodesic,
+) -> f64 {
+    geodesic.inverse(lat1, lon1, lat2, lon2)
+}
+
 fn round(num: f64, precision: u8) -> f64 {
     let multiplier = 10f64.powi(precision as i32);
     (num * multiplier).round() / multiplier
@@ -95,7 +186,12 @@ pub fn bboxes_2d_elementwise(box1: [PointCoords; 4], box2: [PointCoords; 4]) ->
 
 #[cfg(test)]
 mod distance_tests {
-    use crate::distance::{dist_to_segment, bboxes_2d_elementwise, PointCoords};
+    use crate::distance::{
+        bboxes_2d_elementwise, dist_to_segment, euclidean_2d_elementwise,
+        geodesic_bearing_elementwise, geodesic_elementwise, haversine_elementwise, NullPolicy,
+        PointCoords, EARTH_MEAN_RADIUS,
+    };
+    use geographiclib_rs::Geodesic;
 
     #[test]
     fn test_dist_to_segment() {
@@ -136,4 +232,74 @@ mod distance_tests {
                     expected_distance
                 )
     }
+
+    #[test]
+    fn test_geodesic_elementwise() {
+        // reference values computed with GeographicLib
+        let wgs84 = Geodesic::wgs84();
+        let los_angeles = (-118.2884237, 34.095925);
+        let tallinn = (24.7341649, 59.4323439);
+
+        let distance =
+            geodesic_elementwise(los_angeles.0, los_angeles.1, tallinn.0, tallinn.1, &wgs84);
+        assert!((distance - 9094718.72751138).abs() < 1.0e-6);
+
+        let distance = geodesic_elementwise(0.0, 0.0, 180.0, 0.0, &wgs84);
+        assert!((distance - 20003931.4586).abs() < 1.0e-4);
+
+        assert_eq!(geodesic_elementwise(10.0, 20.0, 10.0, 20.0, &wgs84), 0.0);
+    }
+
+    #[test]
+    fn test_haversine_elementwise() {
+        let distance = haversine_elementwise(0.0, 0.0, 0.0, 90.0, EARTH_MEAN_RADIUS);
+        assert!((distance - EARTH_MEAN_RADIUS * std::f64::consts::FRAC_PI_2).abs() < 1.0e-6);
+
+        // spherical approximation stays within 0.5% of the ellipsoidal distance
+        let distance = haversine_elementwise(
+            -118.2884237,
+            34.095925,
+            24.7341649,
+            59.4323439,
+            EARTH_MEAN_RADIUS,
+        );
+        assert!((distance / 9094718.72751138 - 1.0).abs() < 5.0e-3);
+    }
+
+    #[test]
+    fn test_geodesic_bearing_elementwise() {
+        // reference values computed with GeographicLib
+        let wgs84 = Geodesic::wgs84();
+        let (initial, last) = geodesic_bearing_elementwise(
+            -73.78,
+            40.64,
+            49.052487092959836,
+            32.621100463725796,
+            &wgs84,
+        );
+        assert!((initial - 45.0).abs() < 1.0e-9);
+        assert!((last - 140.4059858768007).abs() < 1.0e-9);
+
+        let (initial, last) = geodesic_bearing_elementwise(10.0, 0.0, 9.0, 0.0, &wgs84);
+        assert_eq!((initial, last), (270.0, 270.0));
+    }
+
+    #[test]
+    fn test_null_policy() {
+        let euclidean = |[x1, y1, x2, y2]: [f64; 4]| euclidean_2d_elementwise(x1, y1, x2, y2);
+        let coords = [Some(0.0), Some(0.0), Some(3.0), Some(4.0)];
+
+        for policy in [NullPolicy::Propagate, NullPolicy::Zero, NullPolicy::Raise] {
+            assert_eq!(policy.apply(coords, euclidean), Ok(Some(5.0)));
+        }
+
+        for position in 0..coords.len() {
+            let mut with_null = coords;
+            with_null[position] = None;
+
+            assert_eq!(NullPolicy::Propagate.apply(with_null, euclidean), Ok(None));
+            assert_eq!(NullPolicy::Zero.apply(with_null, euclidean), Ok(Some(0.0)));
+            assert_eq!(NullPolicy::Raise.apply(with_null, euclidean), Err(position));
+        }
+    }
 }
@@ -0,0 +1,185 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferenceEllipsoid {
+    pub semi_major_axis: f64,
+    pub flattening: f64,
+}
+
+impl ReferenceEllipsoid {
+    pub const WGS84: ReferenceEllipsoid = ReferenceEllipsoid {
+        semi_major_axis: 6378137.0,
+        flattening: 1.0 / 298.257223563,
+    };
+
+    pub fn new(semi_major_axis: f64, flattening: f64) -> Option<ReferenceEllipsoid> {
+        if semi_major_axis > 0.0 && (0.0..1.0).contains(&flattening) {
+            Some(ReferenceEllipsoid {
+                semi_major_axis,
+                flattening,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Looks up a reference ellipsoid by its (case-insensitive) name.
+    pub fn from_name(name: &str) -> Option<ReferenceEllipsoid> {
+        let (semi_major_axis, inverse_flattening) = match name.to_lowercase().as_str() {
+            "wgs84" => (6378137.0, 298.257223563),
+            "grs80" => (6378137.0, 298.257222101),
+            "wgs72" => (6378135.0, 298.26),
+            "pz90" => (6378136.0, 298.257839303),
+            "pz90.11" => (6378136.0, 298.25784),
+            "cgcs2000" => (6378137.0, 298.257222101),
+            "krassovsky" | "sk42" => (6378245.0, 298.3),
+            "bessel" => (6377397.155, 299.1528128),
+            "airy" => (6377563.396, 299.3249646),
+            "international" => (6378388.0, 297.0),
+            "clarke1866" => (6378206.4, 294.9786982),
+            _ => return None,
+        };
+        ReferenceEllipsoid::new(semi_major_axis, 1.0 / inverse_flattening)
+    }
+
+    pub fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Radius of curvature in the prime vertical at a latitude given in radians.
+    pub fn radius_normal(&self, lat: f64) -> f64 {
+        self.semi_major_axis / (1.0 - self.eccentricity_squared() * lat.sin().powi(2)).sqrt()
+    }
+}
+
+impl Default for ReferenceEllipsoid {
+    fn default() -> ReferenceEllipsoid {
+        ReferenceEllipsoid::WGS84
+    }
+}
+
+#[cfg(test)]
+mod ellipsoid_tests {
+    use crate::coord_transforms::{ecef_to_lla_elementwise, lla_to_ecef_elementwise};
+    use crate::ellipsoid::ReferenceEllipsoid;
+
+    fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        degrees + minutes / 60.0 + seconds / 3600.0
+    }
+
+    /// Checks `lla` (degrees and metres) against `expected` ECEF coordinates in both
+    /// directions; `tolerance` is in metres, about `tolerance / 1e5` degrees.
+    fn assert_ecef(name: &str, lla: (f64, f64, f64), expected: [f64; 3], tolerance: f64) {
+        let ellipsoid = ReferenceEllipsoid::from_name(name).unwrap();
+        let (x, y, z) = lla_to_ecef_elementwise(lla.0, lla.1, lla.2, &ellipsoid);
+        for (value, expected) in [x, y, z].iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < tolerance,
+                "{}: {:?} != {:?}",
+                name,
+                [x, y, z],
+                expected
+            );
+        }
+
+        let (lon, lat, alt) =
+            ecef_to_lla_elementwise(expected[0], expected[1], expected[2], &ellipsoid);
+        let errors = [
+            (lon - lla.0).abs() * 1.0e5,
+            (lat - lla.1).abs() * 1.0e5,
+            (alt - lla.2).abs(),
+        ];
+        assert!(
+            errors.iter().all(|error| *error < tolerance),
+            "{}: {:?} != {:?}",
+            name,
+            (lon, lat, alt),
+            lla
+        );
+    }
+
+    #[test]
+    fn test_named_ellipsoids() {
+        let grs80 = ReferenceEllipsoid::from_name("GRS80").unwrap();
+        let krassovsky = ReferenceEllipsoid::from_name("krassovsky").unwrap();
+
+        assert_eq!(
+            ReferenceEllipsoid::from_name("wgs84").unwrap(),
+            ReferenceEllipsoid::WGS84
+        );
+        assert!((grs80.semi_minor_axis() - 6356752.314140).abs() < 1.0e-6);
+        assert!((ReferenceEllipsoid::WGS84.semi_minor_axis() - 6356752.314245).abs() < 1.0e-6);
+        assert!((krassovsky.semi_minor_axis() - 6356863.018773).abs() < 1.0e-6);
+        assert!(ReferenceEllipsoid::from_name("unknown").is_none());
+    }
+
+    #[test]
+    fn test_custom_ellipsoid() {
+        assert!(ReferenceEllipsoid::new(6378137.0, 1.0 / 298.257223563).is_some());
+        assert!(ReferenceEllipsoid::new(-1.0, 0.0).is_none());
+        assert!(ReferenceEllipsoid::new(6378137.0, 1.5).is_none());
+    }
+
+    #[test]
+    fn test_published_reference_points() {
+        // EPSG Guidance Note 7-2, geographic/geocentric conversion example.
+        assert_ecef(
+            "wgs84",
+            (dms(2.0, 7.0, 46.38), dms(53.0, 48.0, 33.82), 73.0),
+            [3771793.968, 140253.342, 5124304.349],
+            1.0e-3,
+        );
+        // EPSG Guidance Note 7-2, position vector transformation example (source point).
+        assert_ecef(
+            "wgs72",
+            (4.0, 55.0, 0.0),
+            [3657660.66, 255768.55, 5201382.11],
+            1.0e-2,
+        );
+        // Ordnance Survey, "A guide to coordinate systems in Great Britain", annex B.
+        assert_ecef(
+            "airy",
+            (dms(1.0, 43.0, 4.5177), dms(52.0, 39.0, 27.2531), 24.7),
+            [3874938.849, 116218.624, 5047168.208],
+            1.0e-3,
+        );
+
+        // EPSG Guidance Note 7-2, Molodensky-Badekas transformation example (REGVEN
+        // target point).
+        assert_ecef(
+            "grs80",
+            (-dms(66.0, 4.0, 54.705), dms(9.0, 34.0, 49.001), 180.51),
+            [2550138.46, -5749799.87, 1054530.82],
+            2.0e-2,
+        );
+        // Computed from the published ellipsoid parameters with 40-digit arithmetic.
+        assert_ecef(
+            "krassovsky",
+            (dms(37.0, 37.0, 0.0), dms(55.0, 45.0, 0.0), 150.0),
+            [2850042.2226, 2196148.9939, 5249043.0734],
+            1.0e-3,
+        );
+        assert_ecef(
+            "pz90",
+            (dms(37.0, 37.0, 0.0), dms(55.0, 45.0, 0.0), 150.0),
+            [2849994.4422, 2196112.1760, 5248950.0831],
+            1.0e-3,
+        );
+
+        // The poles lie at the published semi-minor axes.
+        for (name, semi_minor_axis) in [
+            ("grs80", 6356752.314),
+            ("cgcs2000", 6356752.314),
+            ("pz90", 6356751.362),
+            ("pz90.11", 6356751.362),
+            ("krassovsky", 6356863.019),
+            ("bessel", 6356078.963),
+            ("international", 6356911.946),
+            ("clarke1866", 6356583.8),
+        ] {
+            assert_ecef(name, (0.0, 90.0, 0.0), [0.0, 0.0, semi_minor_axis], 1.0e-3);
+        }
+    }
+}
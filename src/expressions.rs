@@ -3,49 +3,387 @@ use polars::datatypes::DataType;
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
 
+use geographiclib_rs::Geodesic;
 use itertools::izip;
+use polars::export::arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use polars::export::arrow::bitmap::Bitmap;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
 
 use crate::coord_transforms::*;
 use crate::distance::*;
+use crate::ellipsoid::ReferenceEllipsoid;
+use crate::quaternions::*;
 use crate::s2_functions::*;
 
-fn unpack_xyz(ca: &StructChunked, lonlat: bool) -> (Series, Series, Series) {
-    let (field_x, field_y, field_z) = if lonlat {
-        ("lon", "lat", "alt")
-    } else {
-        ("x", "y", "z")
+/// Whether coordinates of this dtype can be upcast to `Float64`.
+fn is_coordinate_dtype(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_decimal()
+}
+
+/// Upcasts integer, `Float32` and `Decimal` coordinates to `Float64`.
+fn normalize_coordinates(ser: &Series) -> PolarsResult<Series> {
+    if !is_coordinate_dtype(ser.dtype()) {
+        polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected a numeric dtype",
+            ser.name(),
+            ser.dtype()
+        );
+    }
+    ser.cast(&DataType::Float64)
+}
+
+/// Looks up a numeric field of a struct as `Float64`, naming the expected struct
+/// schema in the error if the field is missing or has another dtype.
+fn struct_field(ca: &StructChunked, name: &str, expected_fields: &[&str]) -> PolarsResult<Series> {
+    let expected_schema = || {
+        expected_fields
+            .iter()
+            .map(|field| format!("{}: numeric", field))
+            .join(", ")
+    };
+    let series = match ca.fields().iter().find(|series| series.name() == name) {
+        Some(series) => series.clone(),
+        None => polars_bail!(
+            ColumnNotFound: "field `{}` not found in struct `{}` with fields [{}]; expected struct {{{}}}",
+            name,
+            ca.name(),
+            ca.fields().iter().map(|series| series.name()).join(", "),
+            expected_schema()
+        ),
+    };
+    if !is_coordinate_dtype(series.dtype()) {
+        polars_bail!(
+            SchemaMismatch: "field `{}` of struct `{}` has dtype {}; expected struct {{{}}}",
+            name,
+            ca.name(),
+            series.dtype(),
+            expected_schema()
+        );
+    }
+    normalize_coordinates(&series)
+}
+
+/// Casts List or Array values of any numeric dtype to `List(Float64)`.
+fn float_lists(ser: &Series) -> PolarsResult<ListChunked> {
+    let list_ser = match ser.dtype() {
+        DataType::Array(inner, _) => ser.cast(&DataType::List(inner.clone()))?,
+        _ => ser.clone(),
     };
+    let inner_ok = match list_ser.dtype() {
+        DataType::List(inner) => is_coordinate_dtype(inner),
+        _ => false,
+    };
+    if !inner_ok {
+        polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected a list or array of numbers",
+            ser.name(),
+            ser.dtype()
+        );
+    }
+    let list_ser = list_ser.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    Ok(list_ser.list()?.clone())
+}
 
-    let x: Series = match ca.field_by_name(field_x) {
-        Ok(series) => series,
-        Err(_) => panic!("Field `x` not found in `{}`!", &ca.name()),
+/// Extracts the coordinate at `position` of List or Array coordinates as `Float64`,
+/// reading the flat value buffers directly; every non-null row must hold a number
+/// of coordinates within `widths`, and rows too short for `position` yield nulls.
+fn list_component(
+    ser: &Series,
+    name: &str,
+    position: usize,
+    widths: RangeInclusive<usize>,
+) -> PolarsResult<Series> {
+    let shape_error = |row: String, len: usize| {
+        polars_err!(
+            ShapeMismatch: "{} of `{}` has {} coordinates; expected {}",
+            row,
+            ser.name(),
+            len,
+            match widths.start() == widths.end() {
+                true => widths.start().to_string(),
+                false => format!("{} to {}", widths.start(), widths.end()),
+            }
+        )
     };
-    let y: Series = match ca.field_by_name(field_y) {
-        Ok(field) => field,
-        Err(_) => panic!("Field `y` not found in `{}`!", &ca.name()),
+
+    let mut chunks: Vec<PrimitiveArray<f64>> = Vec::new();
+    match ser.dtype() {
+        DataType::Array(inner, width) if is_coordinate_dtype(inner) => {
+            if !widths.contains(width) {
+                return Err(shape_error("every row".to_string(), *width));
+            }
+            let arrays = ser.cast(&DataType::Array(Box::new(DataType::Float64), *width))?;
+            for arr in arrays.array()?.downcast_iter() {
+                let values = float_values(arr.values().as_ref());
+                chunks.push(
+                    (0..arr.len())
+                        .map(|row| match arr.is_valid(row) && position < *width {
+                            true => values.get(row * width + position),
+                            false => None,
+                        })
+                        .collect(),
+                );
+            }
+        }
+        DataType::List(inner) if is_coordinate_dtype(inner) => {
+            let lists = ser.cast(&DataType::List(Box::new(DataType::Float64)))?;
+            let mut first_row = 0;
+            for arr in lists.list()?.downcast_iter() {
+                let values = float_values(arr.values().as_ref());
+                let mut out: Vec<Option<f64>> = Vec::with_capacity(arr.len());
+                for row in 0..arr.len() {
+                    if !arr.is_valid(row) {
+                        out.push(None);
+                        continue;
+                    }
+                    let (start, end) = arr.offsets().start_end(row);
+                    if !widths.contains(&(end - start)) {
+                        return Err(shape_error(format!("row {}", first_row + row), end - start));
+                    }
+                    out.push(match start + position < end {
+                        true => values.get(start + position),
+                        false => None,
+                    });
+                }
+                first_row += arr.len();
+                chunks.push(PrimitiveArray::from(out));
+            }
+        }
+        dtype => polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected a list or array of numbers",
+            ser.name(),
+            dtype
+        ),
+    }
+    Ok(Float64Chunked::from_chunk_iter(name, chunks).into_series())
+}
+
+/// The `Float64` values of a List or Array chunk cast to `Float64`.
+fn float_values(values: &dyn Array) -> &PrimitiveArray<f64> {
+    values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .expect("values of a Float64 list or array")
+}
+
+/// Builds an `Array(Float64, width)` series out of row-major coordinates; rows
+/// with all coordinates missing become null.
+fn coordinates_to_array(name: &str, width: usize, values: Vec<Option<f64>>) -> Series {
+    let validity: Bitmap = values
+        .chunks(width)
+        .map(|row| row.iter().any(Option::is_some))
+        .collect();
+    let data_type = FixedSizeListArray::default_datatype(ArrowDataType::Float64, width);
+    let values = PrimitiveArray::<f64>::from(values);
+    let arr = FixedSizeListArray::new(data_type, values.boxed(), Some(validity));
+    ArrayChunked::with_chunk(name, arr).into_series()
+}
+
+/// Converts a `List(Float64)` series whose non-null rows hold `width` values into an
+/// `Array(Float64, width)` series.
+fn list_to_array(ser: &Series, width: usize) -> PolarsResult<Series> {
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(ser.len() * width);
+    for row_op in ser.list()?.into_iter() {
+        match row_op {
+            Some(row) if row.len() == width => values.extend(row.f64()?),
+            Some(row) => polars_bail!(
+                ShapeMismatch: "expected {} values per row, got {}", width, row.len()
+            ),
+            None => values.extend(std::iter::repeat_n(None, width)),
+        }
+    }
+    Ok(coordinates_to_array(ser.name(), width, values))
+}
+
+/// Packs the float fields of a struct into an `Array` of the same float dtype.
+#[polars_expr(output_type_func=struct_to_array_output)]
+fn struct_to_array(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca = inputs[0].struct_()?;
+    let fields = ca.fields();
+    let width = fields.len();
+
+    let mut columns = Vec::with_capacity(width);
+    for field in fields {
+        columns.push(field.cast(&DataType::Float64)?);
+    }
+    let mut iters = Vec::with_capacity(width);
+    for column in &columns {
+        iters.push(column.f64()?.into_iter());
+    }
+
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(ca.len() * width);
+    for _ in 0..ca.len() {
+        values.extend(iters.iter_mut().map(|iter| iter.next().flatten()));
+    }
+
+    let out = coordinates_to_array(ca.name(), width, values);
+    let output_field = struct_to_array_output(&[Field::new(ca.name(), ca.dtype().clone())])?;
+    out.cast(output_field.data_type())
+}
+
+fn struct_to_array_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = match field.data_type() {
+        DataType::Struct(fields) if fields.iter().all(|f| f.data_type() == &DataType::Float32) => {
+            DataType::Float32
+        }
+        DataType::Struct(fields) if fields.iter().all(|f| f.data_type().is_float()) => {
+            DataType::Float64
+        }
+        dtype => polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected a struct of floats", field.name(), dtype
+        ),
     };
-    let z: Series = match ca.field_by_name(field_z) {
-        Ok(field) => field,
-        Err(_) => panic!("Field `z` not found in `{}`!", &ca.name()),
+    let width = match field.data_type() {
+        DataType::Struct(fields) => fields.len(),
+        _ => 0,
     };
-    (x, y, z)
+    Ok(Field::new(
+        field.name(),
+        DataType::Array(Box::new(inner), width),
+    ))
+}
+
+const LLA_FIELDS: [&str; 3] = ["lon", "lat", "alt"];
+
+/// Renaming of input struct fields: either a single mapping shared by all struct
+/// inputs of an expression or one mapping per struct input, in argument order.
+/// Coordinates given as List or Array are read by position instead.
+#[derive(Deserialize, Default)]
+#[serde(transparent)]
+struct FieldMapping(Vec<HashMap<String, String>>);
+
+impl FieldMapping {
+    fn name<'a>(&'a self, input: usize, field: &'a str) -> &'a str {
+        let mapping = match self.0.len() {
+            1 => self.0.first(),
+            _ => self.0.get(input),
+        };
+        mapping
+            .and_then(|mapping| mapping.get(field))
+            .map_or(field, String::as_str)
+    }
+
+    fn field(
+        &self,
+        ser: &Series,
+        input: usize,
+        field: &str,
+        expected_fields: &[&str],
+    ) -> PolarsResult<Series> {
+        let width = expected_fields.len();
+        self.field_within(ser, input, field, expected_fields, width..=width)
+    }
+
+    /// Like `field`, but List or Array rows may hold any number of coordinates
+    /// within `widths`, so trailing `expected_fields` can be left out.
+    fn field_within(
+        &self,
+        ser: &Series,
+        input: usize,
+        field: &str,
+        expected_fields: &[&str],
+        widths: RangeInclusive<usize>,
+    ) -> PolarsResult<Series> {
+        match ser.dtype() {
+            DataType::Struct(_) => {
+                let expected_fields: Vec<&str> = expected_fields
+                    .iter()
+                    .map(|expected| self.name(input, expected))
+                    .collect();
+                struct_field(ser.struct_()?, self.name(input, field), &expected_fields)
+            }
+            DataType::List(_) | DataType::Array(_, _) => {
+                let position = match expected_fields.iter().position(|name| *name == field) {
+                    Some(position) => position,
+                    None => polars_bail!(ComputeError: "unexpected coordinate `{}`!", field),
+                };
+                list_component(ser, field, position, widths)
+            }
+            dtype => polars_bail!(
+                SchemaMismatch: "`{}` has dtype {}; expected a struct, list or array of coordinates",
+                ser.name(),
+                dtype
+            ),
+        }
+    }
+
+    fn unpack_xyz(
+        &self,
+        ser: &Series,
+        input: usize,
+        lonlat: bool,
+    ) -> PolarsResult<(Series, Series, Series)> {
+        let fields = if lonlat { LLA_FIELDS } else { ["x", "y", "z"] };
+
+        Ok((
+            self.field(ser, input, fields[0], &fields)?,
+            self.field(ser, input, fields[1], &fields)?,
+            self.field(ser, input, fields[2], &fields)?,
+        ))
+    }
+
+    fn unpack_xy(&self, ser: &Series, input: usize) -> PolarsResult<(Series, Series)> {
+        let fields = ["x", "y"];
+
+        Ok((
+            self.field(ser, input, "x", &fields)?,
+            self.field(ser, input, "y", &fields)?,
+        ))
+    }
+
+    fn unpack_xyzw(
+        &self,
+        ser: &Series,
+        input: usize,
+    ) -> PolarsResult<(Series, Series, Series, Series)> {
+        let fields = ["x", "y", "z", "w"];
+
+        Ok((
+            self.field(ser, input, "x", &fields)?,
+            self.field(ser, input, "y", &fields)?,
+            self.field(ser, input, "z", &fields)?,
+            self.field(ser, input, "w", &fields)?,
+        ))
+    }
+
+    /// Reads `lon` and `lat` the way `unpack_xyz` reads them with `lonlat`, except that
+    /// `alt` is optional: structs may omit it and lists or arrays may hold 2 or 3 values.
+    fn unpack_lonlat(&self, ser: &Series, input: usize) -> PolarsResult<(Series, Series)> {
+        Ok((
+            self.field_within(ser, input, "lon", &LLA_FIELDS, 2..=3)?,
+            self.field_within(ser, input, "lat", &LLA_FIELDS, 2..=3)?,
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct FieldsKwargs {
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+fn broadcast_to_len(ser: &Series, len: usize) -> PolarsResult<Series> {
+    match ser.len() {
+        1 if len != 1 => Ok(ser.new_from_index(0, len)),
+        n if n == len => Ok(ser.clone()),
+        n => polars_bail!(ShapeMismatch: "expected a series of length {} or 1, got {}", len, n),
+    }
 }
 
 fn apply_rotation_to_map(
-    coords_ca: &StructChunked,
-    rotation_ca: &StructChunked,
-    offset_ca: &StructChunked,
+    coords_ca: &Series,
+    rotation_ca: &Series,
+    offset_ca: &Series,
+    fields: &FieldMapping,
     result_struct_name: &str,
     func_elementwise: impl Fn(Vec<f64>, Vec<f64>, Vec<f64>) -> (f64, f64, f64),
 ) -> Result<StructChunked, PolarsError> {
-    let (x_ser, y_ser, z_ser) = unpack_xyz(coords_ca, false);
-    let (rotation_x, rotation_y, rotation_z) = unpack_xyz(rotation_ca, false);
-    let rotation_w = rotation_ca
-        .field_by_name("w")
-        .expect("Unable to find `w` field for rotation!");
-
-    let (offset_x, offset_y, offset_z) = unpack_xyz(offset_ca, false);
+    let (x_ser, y_ser, z_ser) = fields.unpack_xyz(coords_ca, 0, false)?;
+    let (rotation_x, rotation_y, rotation_z, rotation_w) = fields.unpack_xyzw(rotation_ca, 1)?;
+    let (offset_x, offset_y, offset_z) = fields.unpack_xyz(offset_ca, 2, false)?;
 
     let mut x_cb: PrimitiveChunkedBuilder<Float64Type> =
         PrimitiveChunkedBuilder::new("x", coords_ca.len());
@@ -65,35 +403,57 @@ fn apply_rotation_to_map(
         offset_y_val,
         offset_z_val,
     ) in izip!(
-        x_ser.f64().unwrap(),
-        y_ser.f64().unwrap(),
-        z_ser.f64().unwrap(),
-        rotation_x.f64().unwrap(),
-        rotation_y.f64().unwrap(),
-        rotation_z.f64().unwrap(),
-        rotation_w.f64().unwrap(),
-        offset_x.f64().unwrap(),
-        offset_y.f64().unwrap(),
-        offset_z.f64().unwrap()
+        x_ser.f64()?,
+        y_ser.f64()?,
+        z_ser.f64()?,
+        rotation_x.f64()?,
+        rotation_y.f64()?,
+        rotation_z.f64()?,
+        rotation_w.f64()?,
+        offset_x.f64()?,
+        offset_y.f64()?,
+        offset_z.f64()?
     ) {
-        let map_vec = vec![x_val.unwrap(), y_val.unwrap(), z_val.unwrap()];
-        let rotation_vec = vec![
-            rotation_x_val.unwrap(),
-            rotation_y_val.unwrap(),
-            rotation_z_val.unwrap(),
-            rotation_w_val.unwrap(),
-        ];
-        let offset_vec = vec![
-            offset_x_val.unwrap(),
-            offset_y_val.unwrap(),
-            offset_z_val.unwrap(),
-        ];
-
-        let (x, y, z) = func_elementwise(map_vec, rotation_vec, offset_vec);
-
-        x_cb.append_value(x);
-        y_cb.append_value(y);
-        z_cb.append_value(z);
+        match (
+            x_val,
+            y_val,
+            z_val,
+            rotation_x_val,
+            rotation_y_val,
+            rotation_z_val,
+            rotation_w_val,
+            offset_x_val,
+            offset_y_val,
+            offset_z_val,
+        ) {
+            (
+                Some(x),
+                Some(y),
+                Some(z),
+                Some(rotation_x),
+                Some(rotation_y),
+                Some(rotation_z),
+                Some(rotation_w),
+                Some(offset_x),
+                Some(offset_y),
+                Some(offset_z),
+            ) => {
+                let map_vec = vec![x, y, z];
+                let rotation_vec = vec![rotation_x, rotation_y, rotation_z, rotation_w];
+                let offset_vec = vec![offset_x, offset_y, offset_z];
+
+                let (x, y, z) = func_elementwise(map_vec, rotation_vec, offset_vec);
+
+                x_cb.append_value(x);
+                y_cb.append_value(y);
+                z_cb.append_value(z);
+            }
+            _ => {
+                x_cb.append_null();
+                y_cb.append_null();
+                z_cb.append_null();
+            }
+        }
     }
 
     let ser_out_x = x_cb.finish().into_series();
@@ -104,30 +464,73 @@ fn apply_rotation_to_map(
     out_chunked
 }
 
+fn apply_local_frame(
+    coords_ca: &Series,
+    coords_fields: [&str; 3],
+    origin_ca: &Series,
+    kwargs: &EllipsoidKwargs,
+    result_struct_name: &str,
+    result_fields: [&str; 3],
+    func_elementwise: impl Fn(Vec<f64>, Vec<f64>, &ReferenceEllipsoid) -> (f64, f64, f64),
+) -> PolarsResult<StructChunked> {
+    let ellipsoid = &kwargs.reference_ellipsoid()?;
+    let fields = &kwargs.fields;
+    let x_ser = fields.field(coords_ca, 0, coords_fields[0], &coords_fields)?;
+    let y_ser = fields.field(coords_ca, 0, coords_fields[1], &coords_fields)?;
+    let z_ser = fields.field(coords_ca, 0, coords_fields[2], &coords_fields)?;
+    let (origin_lon, origin_lat, origin_alt) = fields.unpack_xyz(origin_ca, 1, true)?;
+
+    let mut x_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new(result_fields[0], coords_ca.len());
+    let mut y_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new(result_fields[1], coords_ca.len());
+    let mut z_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new(result_fields[2], coords_ca.len());
+
+    for (x_op, y_op, z_op, lon0_op, lat0_op, alt0_op) in izip!(
+        x_ser.f64()?,
+        y_ser.f64()?,
+        z_ser.f64()?,
+        origin_lon.f64()?,
+        origin_lat.f64()?,
+        origin_alt.f64()?
+    ) {
+        match (x_op, y_op, z_op, lon0_op, lat0_op, alt0_op) {
+            (Some(x), Some(y), Some(z), Some(lon0), Some(lat0), Some(alt0)) => {
+                let (x_new, y_new, z_new) =
+                    func_elementwise(vec![x, y, z], vec![lon0, lat0, alt0], ellipsoid);
+                x_cb.append_value(x_new);
+                y_cb.append_value(y_new);
+                z_cb.append_value(z_new);
+            }
+            _ => {
+                x_cb.append_null();
+                y_cb.append_null();
+                z_cb.append_null();
+            }
+        }
+    }
+
+    let ser_out_x = x_cb.finish().into_series();
+    let ser_out_y = y_cb.finish().into_series();
+    let ser_out_z = z_cb.finish().into_series();
+
+    StructChunked::new(result_struct_name, &[ser_out_x, ser_out_y, ser_out_z])
+}
+
 // SSNameSpace
 #[derive(Deserialize)]
 struct S2Kwargs {
     level: u64,
+    #[serde(default)]
+    fields: FieldMapping,
 }
 
 #[polars_expr(output_type=UInt64)]
 fn lonlat_to_cellid(inputs: &[Series], kwargs: S2Kwargs) -> PolarsResult<Series> {
-    let lonlat_ca = inputs[0].struct_()?;
-
-    let lon = lonlat_ca.field_by_name("lon")?;
-    let lat = lonlat_ca.field_by_name("lat")?;
+    let lonlat_ca = &inputs[0];
 
-    let lon: Series = match lon.dtype() {
-        DataType::Float32 => lon.cast(&DataType::Float64)?,
-        DataType::Float64 => lon,
-        _ => polars_bail!(InvalidOperation:"lon must be float32 or float64!"),
-    };
-
-    let lat: Series = match lat.dtype() {
-        DataType::Float32 => lat.cast(&DataType::Float64)?,
-        DataType::Float64 => lat,
-        _ => polars_bail!(InvalidOperation:"lat must be float32 or float64!"),
-    };
+    let (lon, lat) = kwargs.fields.unpack_lonlat(lonlat_ca, 0)?;
 
     let lon_ca = lon.f64()?;
     let lat_ca = lat.f64()?;
@@ -182,13 +585,12 @@ fn cellid_to_lonlat(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Boolean)]
-fn cell_contains_point(inputs: &[Series]) -> PolarsResult<Series> {
+fn cell_contains_point(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
     let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
 
-    let lonlat_ca = inputs[1].struct_()?;
+    let lonlat_ca = &inputs[1];
 
-    let lon_ser = lonlat_ca.field_by_name("lon")?;
-    let lat_ser = lonlat_ca.field_by_name("lat")?;
+    let (lon_ser, lat_ser) = kwargs.fields.unpack_lonlat(lonlat_ca, 0)?;
 
     let lon_ca = lon_ser.f64()?;
     let lat_ca = lat_ser.f64()?;
@@ -267,10 +669,323 @@ fn cellid_to_vertices(inputs: &[Series]) -> PolarsResult<Series> {
     Ok(out_chunked.into_series())
 }
 
+#[derive(Deserialize)]
+struct CellLevelKwargs {
+    level: Option<u64>,
+}
+
+#[polars_expr(output_type=UInt8)]
+fn cellid_level(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let out_ca: UInt8Chunked = cell_ca
+        .into_iter()
+        .map(|cellid_op| cellid_op.and_then(cellid_level_elementwise))
+        .collect_ca("level");
+    Ok(out_ca.into_series())
+}
+
+#[polars_expr(output_type=UInt64)]
+fn cellid_parent(inputs: &[Series], kwargs: CellLevelKwargs) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let level = match kwargs.level {
+        Some(level) => level,
+        None => polars_bail!(ComputeError: "`level` is required!"),
+    };
+    let out_ca: UInt64Chunked = cell_ca
+        .into_iter()
+        .map(|cellid_op| cellid_op.and_then(|cellid| cellid_parent_elementwise(cellid, level)))
+        .collect_ca("s2_cellid");
+    Ok(out_ca.into_series())
+}
+
+#[polars_expr(output_type=UInt64)]
+fn cellid_range_min(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let out_ca: UInt64Chunked = cell_ca
+        .into_iter()
+        .map(|cellid_op| cellid_op.and_then(cellid_range_min_elementwise))
+        .collect_ca("s2_cellid");
+    Ok(out_ca.into_series())
+}
+
+#[polars_expr(output_type=UInt64)]
+fn cellid_range_max(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let out_ca: UInt64Chunked = cell_ca
+        .into_iter()
+        .map(|cellid_op| cellid_op.and_then(cellid_range_max_elementwise))
+        .collect_ca("s2_cellid");
+    Ok(out_ca.into_series())
+}
+
+fn cellid_list_output(_: &[Field]) -> PolarsResult<Field> {
+    let field = Field::new("s2_cellid", DataType::List(Box::new(DataType::UInt64)));
+    Ok(field)
+}
+
+/// Maps every cell ID to a list of cell IDs; `None` gives a null row.
+fn apply_cellid_lists(
+    inputs: &[Series],
+    func_elementwise: impl Fn(u64) -> Option<Vec<u64>>,
+) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let cellid_lists = cell_ca
+        .into_iter()
+        .map(|cellid_op| cellid_op.and_then(&func_elementwise));
+    Ok(collect_cellid_lists(cellid_lists))
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn cellid_children(inputs: &[Series]) -> PolarsResult<Series> {
+    apply_cellid_lists(inputs, cellid_children_elementwise)
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn cellid_edge_neighbors(inputs: &[Series]) -> PolarsResult<Series> {
+    apply_cellid_lists(inputs, cellid_edge_neighbors_elementwise)
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn cellid_all_neighbors(inputs: &[Series], kwargs: CellLevelKwargs) -> PolarsResult<Series> {
+    apply_cellid_lists(inputs, |cellid| {
+        let level = kwargs
+            .level
+            .or(cellid_level_elementwise(cellid).map(u64::from))?;
+        cellid_all_neighbors_elementwise(cellid, level)
+    })
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn cellid_vertex_neighbors(inputs: &[Series], kwargs: CellLevelKwargs) -> PolarsResult<Series> {
+    apply_cellid_lists(inputs, |cellid| {
+        let level = match kwargs.level {
+            Some(level) => level,
+            None => u64::from(cellid_level_elementwise(cellid)?).checked_sub(1)?,
+        };
+        cellid_vertex_neighbors_elementwise(cellid, level)
+    })
+}
+
+#[derive(Deserialize)]
+struct CellAreaKwargs {
+    kind: String,
+    unit: String,
+}
+
+#[polars_expr(output_type=Float64)]
+fn cell_area(inputs: &[Series], kwargs: CellAreaKwargs) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let kind = match CellAreaKind::from_name(&kwargs.kind) {
+        Some(kind) => kind,
+        None => polars_bail!(
+            ComputeError: "unknown cell area kind `{}`, expected one of `approx`, `exact`, `average`!",
+            kwargs.kind
+        ),
+    };
+    // Cell areas are computed on the unit sphere and scaled to the mean Earth radius.
+    let units_per_steradian = match kwargs.unit.as_str() {
+        "steradians" => 1.0,
+        "m2" => EARTH_MEAN_RADIUS.powi(2),
+        "km2" => (EARTH_MEAN_RADIUS / 1000.0).powi(2),
+        unit => polars_bail!(
+            ComputeError: "unknown area unit `{}`, expected one of `steradians`, `m2`, `km2`!",
+            unit
+        ),
+    };
+
+    let out_ca: Float64Chunked = cell_ca
+        .into_iter()
+        .map(|cellid_op| {
+            cellid_op
+                .and_then(|cellid| cell_area_elementwise(cellid, kind))
+                .map(|area| area * units_per_steradian)
+        })
+        .collect_ca("area");
+    Ok(out_ca.into_series())
+}
+
+#[derive(Deserialize)]
+struct TokenKwargs {
+    invalid: String,
+}
+
+impl TokenKwargs {
+    /// Whether invalid values raise an error rather than giving null.
+    fn raise_on_invalid(&self) -> PolarsResult<bool> {
+        match self.invalid.as_str() {
+            "null" => Ok(false),
+            "raise" => Ok(true),
+            invalid => polars_bail!(
+                ComputeError: "unknown `invalid` option `{}`, expected `null` or `raise`!",
+                invalid
+            ),
+        }
+    }
+}
+
+#[polars_expr(output_type=Utf8)]
+fn cellid_to_token(inputs: &[Series], kwargs: TokenKwargs) -> PolarsResult<Series> {
+    let cell_ca: &ChunkedArray<UInt64Type> = inputs[0].u64()?;
+    let raise_on_invalid = kwargs.raise_on_invalid()?;
+
+    let mut out_cb = Utf8ChunkedBuilder::new("s2_token", cell_ca.len(), cell_ca.len() * 16);
+    for (row, cellid_op) in cell_ca.into_iter().enumerate() {
+        let Some(cellid) = cellid_op else {
+            out_cb.append_null();
+            continue;
+        };
+        match cellid_to_token_elementwise(cellid) {
+            Some(token) => out_cb.append_value(token),
+            None if raise_on_invalid => {
+                polars_bail!(ComputeError: "invalid S2 cell ID {} at row {}!", cellid, row)
+            }
+            None => out_cb.append_null(),
+        }
+    }
+    Ok(out_cb.finish().into_series())
+}
+
+#[polars_expr(output_type=UInt64)]
+fn token_to_cellid(inputs: &[Series], kwargs: TokenKwargs) -> PolarsResult<Series> {
+    let token_ca = inputs[0].utf8()?;
+    let raise_on_invalid = kwargs.raise_on_invalid()?;
+
+    let mut out_cb: PrimitiveChunkedBuilder<UInt64Type> =
+        PrimitiveChunkedBuilder::new("s2_cellid", token_ca.len());
+    for (row, token_op) in token_ca.into_iter().enumerate() {
+        let Some(token) = token_op else {
+            out_cb.append_null();
+            continue;
+        };
+        match token_to_cellid_elementwise(token) {
+            Some(cellid) => out_cb.append_value(cellid),
+            None if raise_on_invalid => {
+                polars_bail!(ComputeError: "invalid S2 token `{}` at row {}!", token, row)
+            }
+            None => out_cb.append_null(),
+        }
+    }
+    Ok(out_cb.finish().into_series())
+}
+
+#[derive(Deserialize)]
+struct CoveringKwargs {
+    min_level: u8,
+    max_level: u8,
+    max_cells: usize,
+    interior: bool,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+impl CoveringKwargs {
+    fn options(&self) -> PolarsResult<CoveringOptions> {
+        if self.min_level > self.max_level || self.max_level > 30 {
+            polars_bail!(
+                ComputeError: "expected 0 <= `min_level` <= `max_level` <= 30, got {} and {}!",
+                self.min_level,
+                self.max_level
+            );
+        }
+        if self.max_cells == 0 {
+            polars_bail!(ComputeError: "`max_cells` must be positive!");
+        }
+        Ok(CoveringOptions {
+            min_level: self.min_level,
+            max_level: self.max_level,
+            max_cells: self.max_cells,
+            interior: self.interior,
+        })
+    }
+}
+
+fn collect_cellid_lists(cellid_lists: impl Iterator<Item = Option<Vec<u64>>>) -> Series {
+    let (len, _) = cellid_lists.size_hint();
+    let mut list_builder: ListPrimitiveChunkedBuilder<UInt64Type> =
+        ListPrimitiveChunkedBuilder::new("s2_cellid", len, 8, DataType::UInt64);
+    for cellids_op in cellid_lists {
+        match cellids_op {
+            Some(cellids) => list_builder.append_slice(&cellids),
+            None => list_builder.append_null(),
+        }
+    }
+    list_builder.finish().into_series()
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn polygon_covering(inputs: &[Series], kwargs: CoveringKwargs) -> PolarsResult<Series> {
+    let options = kwargs.options()?;
+    let polygons = match inputs[0].dtype() {
+        DataType::Array(inner, _) => inputs[0].cast(&DataType::List(inner.clone()))?,
+        _ => inputs[0].clone(),
+    };
+
+    let mut coverings: Vec<Option<Vec<u64>>> = Vec::with_capacity(polygons.len());
+    for polygon_op in polygons.list()?.into_iter() {
+        let Some(polygon) = polygon_op else {
+            coverings.push(None);
+            continue;
+        };
+        let (lon, lat) = kwargs.fields.unpack_lonlat(&polygon, 0)?;
+        let vertices: Option<Vec<(f64, f64)>> = lon
+            .f64()?
+            .into_iter()
+            .zip(lat.f64()?.into_iter())
+            .map(|(lon_op, lat_op)| Some((lon_op?, lat_op?)))
+            .collect();
+        coverings
+            .push(vertices.and_then(|vertices| polygon_covering_elementwise(&vertices, options)));
+    }
+    Ok(collect_cellid_lists(coverings.into_iter()))
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn cap_covering(inputs: &[Series], kwargs: CoveringKwargs) -> PolarsResult<Series> {
+    let options = kwargs.options()?;
+    let (lon, lat) = kwargs.fields.unpack_lonlat(&inputs[0], 0)?;
+    let radius = normalize_coordinates(&broadcast_to_len(&inputs[1], lon.len())?)?;
+
+    let coverings =
+        izip!(lon.f64()?, lat.f64()?, radius.f64()?).map(|(lon_op, lat_op, radius_op)| {
+            match (lon_op, lat_op, radius_op) {
+                // The radius in metres is an arc of the sphere with the mean Earth radius.
+                (Some(lon), Some(lat), Some(radius)) => Some(cap_covering_elementwise(
+                    lon,
+                    lat,
+                    radius / EARTH_MEAN_RADIUS,
+                    options,
+                )),
+                _ => None,
+            }
+        });
+    Ok(collect_cellid_lists(coverings))
+}
+
+#[polars_expr(output_type_func=cellid_list_output)]
+fn rect_covering(inputs: &[Series], kwargs: CoveringKwargs) -> PolarsResult<Series> {
+    let options = kwargs.options()?;
+    let hi_ser = broadcast_to_len(&inputs[1], inputs[0].len())?;
+    let (lo_lon, lo_lat) = kwargs.fields.unpack_lonlat(&inputs[0], 0)?;
+    let (hi_lon, hi_lat) = kwargs.fields.unpack_lonlat(&hi_ser, 1)?;
+
+    let coverings =
+        izip!(lo_lon.f64()?, lo_lat.f64()?, hi_lon.f64()?, hi_lat.f64()?).map(|corners| {
+            match corners {
+                (Some(lo_lon), Some(lo_lat), Some(hi_lon), Some(hi_lat)) => Some(
+                    rect_covering_elementwise((lo_lon, lo_lat), (hi_lon, hi_lat), options),
+                ),
+                _ => None,
+            }
+        });
+    Ok(collect_cellid_lists(coverings))
+}
+
 //TransfromNameSpace
 #[derive(Deserialize)]
 struct TransformInterpolateKwargs {
-    coef: f64,
+    extrapolate: bool,
+    #[serde(default)]
+    fields: FieldMapping,
 }
 
 fn output_3d(_: &[Field]) -> PolarsResult<Field> {
@@ -287,11 +1002,20 @@ fn interpolate_linear(
     inputs: &[Series],
     kwargs: TransformInterpolateKwargs,
 ) -> PolarsResult<Series> {
-    let ca = inputs[0].struct_()?;
-    let ca_other = inputs[1].struct_()?;
+    interpolate_points(inputs, &kwargs)
+}
 
-    let (x_ser, y_ser, z_ser) = unpack_xyz(ca, false);
-    let (x_other_ser, y_other_ser, z_other_ser) = unpack_xyz(ca_other, false);
+fn interpolate_points(
+    inputs: &[Series],
+    kwargs: &TransformInterpolateKwargs,
+) -> PolarsResult<Series> {
+    let ca = &inputs[0];
+    let ca_other = &inputs[1];
+
+    let coef_ser = interpolation_parameter(&inputs[2], ca.len())?;
+
+    let (x_ser, y_ser, z_ser) = kwargs.fields.unpack_xyz(ca, 0, false)?;
+    let (x_other_ser, y_other_ser, z_other_ser) = kwargs.fields.unpack_xyz(ca_other, 1, false)?;
 
     let mut x_cb: PrimitiveChunkedBuilder<Float64Type> =
         PrimitiveChunkedBuilder::new("x", ca.len());
@@ -300,21 +1024,41 @@ fn interpolate_linear(
     let mut z_cb: PrimitiveChunkedBuilder<Float64Type> =
         PrimitiveChunkedBuilder::new("z", ca.len());
 
-    for (x_op, y_op, z_op, x_other_op, y_other_op, z_other_op) in izip!(
+    for (row, (x_op, y_op, z_op, x_other_op, y_other_op, z_other_op, coef_op)) in izip!(
         x_ser.f64()?.into_iter(),
         y_ser.f64()?.into_iter(),
         z_ser.f64()?.into_iter(),
         x_other_ser.f64()?.into_iter(),
         y_other_ser.f64()?.into_iter(),
-        z_other_ser.f64()?.into_iter()
-    ) {
-        match (x_op, y_op, z_op, x_other_op, y_other_op, z_other_op) {
-            (Some(x), Some(y), Some(z), Some(x_other), Some(y_other), Some(z_other)) => {
+        z_other_ser.f64()?.into_iter(),
+        coef_ser.f64()?.into_iter()
+    )
+    .enumerate()
+    {
+        match (
+            x_op, y_op, z_op, x_other_op, y_other_op, z_other_op, coef_op,
+        ) {
+            (
+                Some(x),
+                Some(y),
+                Some(z),
+                Some(x_other),
+                Some(y_other),
+                Some(z_other),
+                Some(coef),
+            ) => {
+                if !kwargs.extrapolate && !(0.0..=1.0).contains(&coef) {
+                    polars_bail!(
+                        ComputeError: "`coef` is {} at row {}; it must be between 0 and 1 unless `extrapolate` is set!",
+                        coef,
+                        row
+                    );
+                }
                 let (x_interpolated, y_interpolated, z_interpolated) =
                     interpolate_linear_elementwise(
                         vec![x, y, z],
                         vec![x_other, y_other, z_other],
-                        kwargs.coef,
+                        coef,
                     );
                 x_cb.append_value(x_interpolated);
                 y_cb.append_value(y_interpolated);
@@ -346,15 +1090,16 @@ fn ecef_output(_: &[Field]) -> PolarsResult<Field> {
 }
 
 #[polars_expr(output_type_func=ecef_output)]
-fn map_to_ecef(inputs: &[Series]) -> PolarsResult<Series> {
-    let map_ca = inputs[0].struct_()?;
-    let rotation_ca = inputs[1].struct_()?;
-    let offset_ca = inputs[2].struct_()?;
+fn map_to_ecef(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let map_ca = &inputs[0];
+    let rotation_ca = &inputs[1];
+    let offset_ca = &inputs[2];
 
     let out_chunked = apply_rotation_to_map(
         map_ca,
         rotation_ca,
         offset_ca,
+        &kwargs.fields,
         "ecef",
         map_to_ecef_elementwise,
     );
@@ -372,21 +1117,54 @@ fn map_output(_: &[Field]) -> PolarsResult<Field> {
 }
 
 #[polars_expr(output_type_func=map_output)]
-fn ecef_to_map(inputs: &[Series]) -> PolarsResult<Series> {
-    let ecef_ca = inputs[0].struct_()?;
-    let rotation_ca = inputs[1].struct_()?;
-    let offset_ca = inputs[2].struct_()?;
+fn ecef_to_map(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let ecef_ca = &inputs[0];
+    let rotation_ca = &inputs[1];
+    let offset_ca = &inputs[2];
 
     let out_chunked = apply_rotation_to_map(
         ecef_ca,
         rotation_ca,
         offset_ca,
+        &kwargs.fields,
         "map",
         ecef_to_map_elementwise,
     );
     Ok(out_chunked?.into_series())
 }
 
+#[derive(Deserialize)]
+struct EllipsoidKwargs {
+    ellipsoid: String,
+    semi_major_axis: Option<f64>,
+    flattening: Option<f64>,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+fn parse_reference_ellipsoid(
+    name: &str,
+    semi_major_axis: Option<f64>,
+    flattening: Option<f64>,
+) -> PolarsResult<ReferenceEllipsoid> {
+    let ellipsoid = match (semi_major_axis, flattening) {
+        (Some(semi_major_axis), Some(flattening)) => {
+            ReferenceEllipsoid::new(semi_major_axis, flattening)
+        }
+        _ => ReferenceEllipsoid::from_name(name),
+    };
+    match ellipsoid {
+        Some(ellipsoid) => Ok(ellipsoid),
+        None => polars_bail!(ComputeError: "invalid reference ellipsoid `{}`!", name),
+    }
+}
+
+impl EllipsoidKwargs {
+    fn reference_ellipsoid(&self) -> PolarsResult<ReferenceEllipsoid> {
+        parse_reference_ellipsoid(&self.ellipsoid, self.semi_major_axis, self.flattening)
+    }
+}
+
 fn lla_output(_: &[Field]) -> PolarsResult<Field> {
     let v: Vec<Field> = vec![
         Field::new("lon", DataType::Float64),
@@ -397,10 +1175,11 @@ fn lla_output(_: &[Field]) -> PolarsResult<Field> {
 }
 
 #[polars_expr(output_type_func=lla_output)]
-fn ecef_to_lla(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca = inputs[0].struct_()?;
+fn ecef_to_lla(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let ca = &inputs[0];
+    let ellipsoid = kwargs.reference_ellipsoid()?;
 
-    let (ecef_x_ser, ecef_y_ser, ecef_z_ser) = unpack_xyz(ca, false);
+    let (ecef_x_ser, ecef_y_ser, ecef_z_ser) = kwargs.fields.unpack_xyz(ca, 0, false)?;
 
     let ecef_x = ecef_x_ser.f64()?;
     let ecef_y = ecef_y_ser.f64()?;
@@ -416,7 +1195,7 @@ fn ecef_to_lla(inputs: &[Series]) -> PolarsResult<Series> {
     for (x, y, z) in izip!(ecef_x.into_iter(), ecef_y.into_iter(), ecef_z.into_iter()) {
         match (x, y, z) {
             (Some(x), Some(y), Some(z)) => {
-                let (lon, lat, alt) = ecef_to_lla_elementwise(x, y, z);
+                let (lon, lat, alt) = ecef_to_lla_elementwise(x, y, z, &ellipsoid);
                 longitude.append_value(lon);
                 latitude.append_value(lat);
                 altitude.append_value(alt);
@@ -438,10 +1217,11 @@ fn ecef_to_lla(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type_func=ecef_output)]
-fn lla_to_ecef(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca = inputs[0].struct_()?;
+fn lla_to_ecef(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let ca = &inputs[0];
+    let ellipsoid = kwargs.reference_ellipsoid()?;
 
-    let (lon_ser, lat_ser, alt_ser) = unpack_xyz(ca, true);
+    let (lon_ser, lat_ser, alt_ser) = kwargs.fields.unpack_xyz(ca, 0, true)?;
 
     let lon = lon_ser.f64()?;
     let lat = lat_ser.f64()?;
@@ -457,7 +1237,7 @@ fn lla_to_ecef(inputs: &[Series]) -> PolarsResult<Series> {
     for (lon_op, lat_op, alt_op) in izip!(lon.into_iter(), lat.into_iter(), alt.into_iter()) {
         match (lon_op, lat_op, alt_op) {
             (Some(lo), Some(la), Some(al)) => {
-                let (x, y, z) = lla_to_ecef_elementwise(lo, la, al);
+                let (x, y, z) = lla_to_ecef_elementwise(lo, la, al, &ellipsoid);
                 ecef_x.append_value(x);
                 ecef_y.append_value(y);
                 ecef_z.append_value(z);
@@ -478,31 +1258,333 @@ fn lla_to_ecef(inputs: &[Series]) -> PolarsResult<Series> {
     Ok(out_chunked.into_series())
 }
 
-fn utm_output(_: &[Field]) -> PolarsResult<Field> {
+fn enu_output(_: &[Field]) -> PolarsResult<Field> {
     let v: Vec<Field> = vec![
         Field::new("x", DataType::Float64),
         Field::new("y", DataType::Float64),
         Field::new("z", DataType::Float64),
     ];
-    Ok(Field::new("utm", DataType::Struct(v)))
+    Ok(Field::new("enu", DataType::Struct(v)))
 }
 
-#[polars_expr(output_type_func=utm_output)]
-fn lla_to_utm(inputs: &[Series]) -> PolarsResult<Series> {
-    let coords_ca = inputs[0].struct_()?;
-    let (lon_ser, lat_ser, alt_ser) = unpack_xyz(coords_ca, true);
+fn ned_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = vec![
+        Field::new("x", DataType::Float64),
+        Field::new("y", DataType::Float64),
+        Field::new("z", DataType::Float64),
+    ];
+    Ok(Field::new("ned", DataType::Struct(v)))
+}
 
-    let mut utm_x: PrimitiveChunkedBuilder<Float64Type> =
-        PrimitiveChunkedBuilder::new("x", coords_ca.len());
-    let mut utm_y: PrimitiveChunkedBuilder<Float64Type> =
-        PrimitiveChunkedBuilder::new("y", coords_ca.len());
-    let mut utm_z: PrimitiveChunkedBuilder<Float64Type> =
-        PrimitiveChunkedBuilder::new("z", coords_ca.len());
+#[polars_expr(output_type_func=enu_output)]
+fn ecef_to_enu(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "enu",
+        ["x", "y", "z"],
+        ecef_to_enu_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
 
-    for (lon_op, lat_op, alt_op) in izip!(lon_ser.f64()?, lat_ser.f64()?, alt_ser.f64()?) {
-        match (lon_op, lat_op, alt_op) {
-            (Some(lon), Some(lat), Some(alt)) => {
-                let (easting, northing, alt) = lla_to_utm_elementwise(lon, lat, alt);
+#[polars_expr(output_type_func=ecef_output)]
+fn enu_to_ecef(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "ecef",
+        ["x", "y", "z"],
+        enu_to_ecef_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=enu_output)]
+fn lla_to_enu(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["lon", "lat", "alt"],
+        &origin_ser,
+        &kwargs,
+        "enu",
+        ["x", "y", "z"],
+        lla_to_enu_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=lla_output)]
+fn enu_to_lla(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "coordinates",
+        ["lon", "lat", "alt"],
+        enu_to_lla_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=ned_output)]
+fn ecef_to_ned(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "ned",
+        ["x", "y", "z"],
+        ecef_to_ned_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=ecef_output)]
+fn ned_to_ecef(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "ecef",
+        ["x", "y", "z"],
+        ned_to_ecef_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=ned_output)]
+fn lla_to_ned(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["lon", "lat", "alt"],
+        &origin_ser,
+        &kwargs,
+        "ned",
+        ["x", "y", "z"],
+        lla_to_ned_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=lla_output)]
+fn ned_to_lla(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let origin_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["x", "y", "z"],
+        &origin_ser,
+        &kwargs,
+        "coordinates",
+        ["lon", "lat", "alt"],
+        ned_to_lla_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+fn aer_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = vec![
+        Field::new("azimuth", DataType::Float64),
+        Field::new("elevation", DataType::Float64),
+        Field::new("range", DataType::Float64),
+    ];
+    Ok(Field::new("aer", DataType::Struct(v)))
+}
+
+#[polars_expr(output_type_func=aer_output)]
+fn lla_to_aer(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let observer_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["lon", "lat", "alt"],
+        &observer_ser,
+        &kwargs,
+        "aer",
+        ["azimuth", "elevation", "range"],
+        lla_to_aer_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=lla_output)]
+fn aer_to_lla(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let observer_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let out_chunked = apply_local_frame(
+        coords_ca,
+        ["azimuth", "elevation", "range"],
+        &observer_ser,
+        &kwargs,
+        "coordinates",
+        ["lon", "lat", "alt"],
+        aer_to_lla_elementwise,
+    );
+    Ok(out_chunked?.into_series())
+}
+
+#[polars_expr(output_type_func=lla_output)]
+fn destination(inputs: &[Series], kwargs: EllipsoidKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let (lon_ser, lat_ser, alt_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, true)?;
+    let bearing_ser = broadcast_to_len(&normalize_coordinates(&inputs[1])?, coords_ca.len())?;
+    let distance_ser = broadcast_to_len(&normalize_coordinates(&inputs[2])?, coords_ca.len())?;
+
+    let ellipsoid = kwargs.reference_ellipsoid()?;
+    let geodesic = Geodesic::new(ellipsoid.semi_major_axis, ellipsoid.flattening);
+
+    let mut longitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("lon", coords_ca.len());
+    let mut latitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("lat", coords_ca.len());
+    let mut altitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("alt", coords_ca.len());
+
+    for (lon_op, lat_op, alt_op, bearing_op, distance_op) in izip!(
+        lon_ser.f64()?,
+        lat_ser.f64()?,
+        alt_ser.f64()?,
+        bearing_ser.f64()?,
+        distance_ser.f64()?
+    ) {
+        match (lon_op, lat_op, alt_op, bearing_op, distance_op) {
+            (Some(lon), Some(lat), Some(alt), Some(bearing), Some(distance)) => {
+                let (lon_new, lat_new, alt_new) =
+                    geodesic_destination_elementwise(lon, lat, alt, bearing, distance, &geodesic);
+                longitude.append_value(lon_new);
+                latitude.append_value(lat_new);
+                altitude.append_value(alt_new);
+            }
+            _ => {
+                longitude.append_null();
+                latitude.append_null();
+                altitude.append_null();
+            }
+        }
+    }
+
+    let ser_lon = longitude.finish().into_series();
+    let ser_lat = latitude.finish().into_series();
+    let ser_alt = altitude.finish().into_series();
+
+    let out_chunked = StructChunked::new("coordinates", &[ser_lon, ser_lat, ser_alt])?;
+    Ok(out_chunked.into_series())
+}
+
+fn utm_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = vec![
+        Field::new("x", DataType::Float64),
+        Field::new("y", DataType::Float64),
+        Field::new("z", DataType::Float64),
+    ];
+    Ok(Field::new("utm", DataType::Struct(v)))
+}
+
+#[derive(Deserialize)]
+struct UtmKwargs {
+    zone: Option<u8>,
+    zone_mode: String,
+    zone_exceptions: bool,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+fn resolve_utm_zone_numbers(
+    lon_ca: &Float64Chunked,
+    lat_ca: &Float64Chunked,
+    kwargs: &UtmKwargs,
+) -> PolarsResult<UInt8Chunked> {
+    let point_zones: UInt8Chunked = izip!(lon_ca, lat_ca)
+        .map(|(lon_op, lat_op)| match (lon_op, lat_op) {
+            (Some(lon), Some(lat)) if kwargs.zone_exceptions => {
+                Some(lla_to_utm_zone_number_elementwise(lon, lat))
+            }
+            (Some(lon), Some(_)) => Some(lla_to_utm_zone_number_regular_elementwise(lon)),
+            _ => None,
+        })
+        .collect_ca("utm_zone_number");
+
+    let common_zone: Option<u8> = match (kwargs.zone, kwargs.zone_mode.as_str()) {
+        (Some(zone), _) => {
+            if !(1..=60).contains(&zone) {
+                polars_bail!(ComputeError: "UTM zone number must be between 1 and 60, got {}!", zone);
+            }
+            Some(zone)
+        }
+        (None, "point") => return Ok(point_zones),
+        (None, "first") => point_zones.into_iter().flatten().next(),
+        (None, "majority") => {
+            let mut counts = [0usize; 61];
+            for zone in point_zones.into_iter().flatten() {
+                counts[zone as usize] += 1;
+            }
+            (1..=60u8)
+                .filter(|zone| counts[*zone as usize] > 0)
+                .max_by_key(|zone| (counts[*zone as usize], std::cmp::Reverse(*zone)))
+        }
+        (None, mode) => polars_bail!(ComputeError: "unknown UTM zone mode `{}`!", mode),
+    };
+
+    Ok(point_zones
+        .into_iter()
+        .map(|zone_op| zone_op.and(common_zone))
+        .collect_ca("utm_zone_number"))
+}
+
+#[polars_expr(output_type_func=utm_output)]
+fn lla_to_utm(inputs: &[Series], kwargs: UtmKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let (lon_ser, lat_ser, alt_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, true)?;
+    let zone_numbers = resolve_utm_zone_numbers(lon_ser.f64()?, lat_ser.f64()?, &kwargs)?;
+
+    let mut utm_x: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("x", coords_ca.len());
+    let mut utm_y: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("y", coords_ca.len());
+    let mut utm_z: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("z", coords_ca.len());
+
+    for (lon_op, lat_op, alt_op, zone_number_op) in izip!(
+        lon_ser.f64()?,
+        lat_ser.f64()?,
+        alt_ser.f64()?,
+        &zone_numbers
+    ) {
+        match (lon_op, lat_op, alt_op, zone_number_op) {
+            (Some(lon), Some(lat), Some(alt), Some(zone_number)) => {
+                let (easting, northing, alt) = lla_to_utm_elementwise(lon, lat, alt, zone_number);
                 utm_x.append_value(easting);
                 utm_y.append_value(northing);
                 utm_z.append_value(alt);
@@ -523,38 +1605,185 @@ fn lla_to_utm(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=UInt8)]
-fn lla_to_utm_zone_number(inputs: &[Series]) -> PolarsResult<Series> {
-    let coords_ca = inputs[0].struct_()?;
-    let (lon_ser, lat_ser, _alt_ser) = unpack_xyz(coords_ca, true);
+fn lla_to_utm_zone_number(inputs: &[Series], kwargs: UtmKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let (lon_ser, lat_ser, _alt_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, true)?;
+
+    let zone_numbers = resolve_utm_zone_numbers(lon_ser.f64()?, lat_ser.f64()?, &kwargs)?;
+    Ok(zone_numbers.into_series())
+}
+
+fn utm_full_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = vec![
+        Field::new("x", DataType::Float64),
+        Field::new("y", DataType::Float64),
+        Field::new("z", DataType::Float64),
+        Field::new("zone_number", DataType::UInt8),
+        Field::new("zone_letter", DataType::Utf8),
+        Field::new("hemisphere", DataType::Utf8),
+        Field::new("convergence", DataType::Float64),
+        Field::new("scale_factor", DataType::Float64),
+    ];
+    Ok(Field::new("utm", DataType::Struct(v)))
+}
+
+#[polars_expr(output_type_func=utm_full_output)]
+fn lla_to_utm_full(inputs: &[Series], kwargs: UtmKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let (lon_ser, lat_ser, alt_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, true)?;
+    let zone_numbers = resolve_utm_zone_numbers(lon_ser.f64()?, lat_ser.f64()?, &kwargs)?;
+    let len = coords_ca.len();
+
+    let mut utm_x: PrimitiveChunkedBuilder<Float64Type> = PrimitiveChunkedBuilder::new("x", len);
+    let mut utm_y: PrimitiveChunkedBuilder<Float64Type> = PrimitiveChunkedBuilder::new("y", len);
+    let mut utm_z: PrimitiveChunkedBuilder<Float64Type> = PrimitiveChunkedBuilder::new("z", len);
+    let mut zone_number_cb: PrimitiveChunkedBuilder<UInt8Type> =
+        PrimitiveChunkedBuilder::new("zone_number", len);
+    let mut zone_letter_cb = Utf8ChunkedBuilder::new("zone_letter", len, len);
+    let mut hemisphere_cb = Utf8ChunkedBuilder::new("hemisphere", len, len * 5);
+    let mut convergence_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("convergence", len);
+    let mut scale_factor_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("scale_factor", len);
+
+    for (lon_op, lat_op, alt_op, zone_number_op) in izip!(
+        lon_ser.f64()?,
+        lat_ser.f64()?,
+        alt_ser.f64()?,
+        &zone_numbers
+    ) {
+        match (lon_op, lat_op, alt_op, zone_number_op) {
+            (Some(lon), Some(lat), Some(alt), Some(zone_number)) => {
+                let utm = lla_to_utm_full_elementwise(lon, lat, alt, zone_number);
+                utm_x.append_value(utm.easting);
+                utm_y.append_value(utm.northing);
+                utm_z.append_value(utm.alt);
+                zone_number_cb.append_value(utm.zone_number);
+                zone_letter_cb.append_option(utm.zone_letter.map(String::from));
+                hemisphere_cb.append_value(if utm.northern { "north" } else { "south" });
+                convergence_cb.append_value(utm.convergence);
+                scale_factor_cb.append_value(utm.scale_factor);
+            }
+            _ => {
+                utm_x.append_null();
+                utm_y.append_null();
+                utm_z.append_null();
+                zone_number_cb.append_null();
+                zone_letter_cb.append_null();
+                hemisphere_cb.append_null();
+                convergence_cb.append_null();
+                scale_factor_cb.append_null();
+            }
+        }
+    }
+
+    let out_chunked = StructChunked::new(
+        "utm",
+        &[
+            utm_x.finish().into_series(),
+            utm_y.finish().into_series(),
+            utm_z.finish().into_series(),
+            zone_number_cb.finish().into_series(),
+            zone_letter_cb.finish().into_series(),
+            hemisphere_cb.finish().into_series(),
+            convergence_cb.finish().into_series(),
+            scale_factor_cb.finish().into_series(),
+        ],
+    )?;
+    Ok(out_chunked.into_series())
+}
 
-    let mut utm_number_cb: PrimitiveChunkedBuilder<UInt8Type> =
-        PrimitiveChunkedBuilder::new("utm_zone_number", coords_ca.len());
+/// Whether a hemisphere (`"north"`/`"south"`) or a UTM zone letter (`C`-`X`) is northern.
+/// Single characters are always read as zone letters, so `S` (32-40°N) is northern.
+fn parse_utm_hemisphere(value: &str) -> PolarsResult<bool> {
+    let mut chars = value.chars();
+    if let (Some(letter), None) = (chars.next(), chars.next()) {
+        return match letter.to_ascii_uppercase() {
+            'C'..='H' | 'J'..='M' => Ok(false),
+            'N' | 'P'..='X' => Ok(true),
+            _ => polars_bail!(ComputeError: "invalid UTM zone letter `{}`!", value),
+        };
+    }
+    match value.to_lowercase().as_str() {
+        "north" => Ok(true),
+        "south" => Ok(false),
+        _ => polars_bail!(ComputeError: "invalid UTM hemisphere `{}`!", value),
+    }
+}
 
-    for (lon_op, lat_op) in izip!(lon_ser.f64()?, lat_ser.f64()?,) {
-        match (lon_op, lat_op) {
-            (Some(lon), Some(lat)) => {
-                let utm_number = lla_to_utm_zone_number_elementwise(lon, lat);
-                utm_number_cb.append_value(utm_number);
+/// UTM zone numbers as `UInt8`, broadcast to `len`; fails on zones outside 1..=60
+/// instead of letting the cast turn them into nulls.
+fn utm_zone_numbers(ser: &Series, len: usize) -> PolarsResult<Series> {
+    let zone_numbers = ser.cast(&DataType::Int64)?;
+    for (row, zone_number) in zone_numbers.i64()?.into_iter().enumerate() {
+        if let Some(zone_number) = zone_number.filter(|zone| !(1..=60).contains(zone)) {
+            polars_bail!(
+                ComputeError: "UTM zone number must be between 1 and 60, got {} at row {}!",
+                zone_number,
+                row
+            );
+        }
+    }
+    broadcast_to_len(&zone_numbers.cast(&DataType::UInt8)?, len)
+}
+
+#[polars_expr(output_type_func=lla_output)]
+fn utm_to_lla(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let (x_ser, y_ser, z_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, false)?;
+
+    let zone_number_ser = utm_zone_numbers(&inputs[1], coords_ca.len())?;
+    let hemisphere_ser = broadcast_to_len(&inputs[2], coords_ca.len())?;
+
+    let mut longitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("lon", coords_ca.len());
+    let mut latitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("lat", coords_ca.len());
+    let mut altitude: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("alt", coords_ca.len());
+
+    for (x_op, y_op, z_op, zone_number_op, hemisphere_op) in izip!(
+        x_ser.f64()?,
+        y_ser.f64()?,
+        z_ser.f64()?,
+        zone_number_ser.u8()?,
+        hemisphere_ser.utf8()?
+    ) {
+        match (x_op, y_op, z_op, zone_number_op, hemisphere_op) {
+            (Some(x), Some(y), Some(z), Some(zone_number), Some(hemisphere)) => {
+                let northern = parse_utm_hemisphere(hemisphere)?;
+                let (lon, lat, alt) = utm_to_lla_elementwise(x, y, z, zone_number, northern);
+                longitude.append_value(lon);
+                latitude.append_value(lat);
+                altitude.append_value(alt);
             }
             _ => {
-                utm_number_cb.append_null();
+                longitude.append_null();
+                latitude.append_null();
+                altitude.append_null();
             }
         }
     }
-    let ser_utm_number = utm_number_cb.finish().into_series();
-    Ok(ser_utm_number)
+
+    let ser_lon = longitude.finish().into_series();
+    let ser_lat = latitude.finish().into_series();
+    let ser_alt = altitude.finish().into_series();
+
+    let out_chunked = StructChunked::new("coordinates", &[ser_lon, ser_lat, ser_alt])?;
+    Ok(out_chunked.into_series())
 }
 
 #[polars_expr(output_type_func=map_output)]
-fn rotate_map_coords(inputs: &[Series]) -> PolarsResult<Series> {
-    let map_ca = inputs[0].struct_()?;
-    let rotation_ca = inputs[1].struct_()?;
-    let scale_ca = inputs[2].struct_()?;
+fn rotate_map_coords(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let map_ca = &inputs[0];
+    let rotation_ca = &inputs[1];
+    let scale_ca = &inputs[2];
 
     let out_chunked = apply_rotation_to_map(
         map_ca,
         rotation_ca,
         scale_ca,
+        &kwargs.fields,
         "map",
         rotate_map_coords_elementwise,
     );
@@ -572,8 +1801,8 @@ fn euler_angles_output(_: &[Field]) -> PolarsResult<Field> {
 }
 
 #[polars_expr(output_type_func=euler_angles_output)]
-fn quat_to_euler_angles(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca = inputs[0].struct_()?;
+fn quat_to_euler_angles(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let ca = &inputs[0];
     let mut roll_cb: PrimitiveChunkedBuilder<Float64Type> =
         PrimitiveChunkedBuilder::new("roll", ca.len());
     let mut pitch_cb: PrimitiveChunkedBuilder<Float64Type> =
@@ -581,8 +1810,7 @@ fn quat_to_euler_angles(inputs: &[Series]) -> PolarsResult<Series> {
     let mut yaw_cb: PrimitiveChunkedBuilder<Float64Type> =
         PrimitiveChunkedBuilder::new("yaw", ca.len());
 
-    let (x, y, z) = unpack_xyz(ca, false);
-    let w = ca.field_by_name("w")?;
+    let (x, y, z, w) = kwargs.fields.unpack_xyzw(ca, 0)?;
 
     for (x_op, y_op, z_op, w_op) in izip!(x.f64()?, y.f64()?, z.f64()?, w.f64()?) {
         match (x_op, y_op, z_op, w_op) {
@@ -616,18 +1844,30 @@ fn rotation_matrix_output(_: &[Field]) -> PolarsResult<Field> {
 }
 
 #[polars_expr(output_type_func=rotation_matrix_output)]
-fn get_rotation_matrix(inputs: &[Series]) -> PolarsResult<Series> {
+fn get_rotation_matrix(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    rotation_matrices(inputs, &kwargs.fields)
+}
 
+fn rotation_matrix_array_output(_: &[Field]) -> PolarsResult<Field> {
+    let field = Field::new(
+        "rotation_matrix",
+        DataType::Array(Box::new(DataType::Float64), 16),
+    );
+    Ok(field)
+}
 
-    let rotation_ca = inputs[0].struct_()?;
-    let offset_ca = inputs[1].struct_()?;
+#[polars_expr(output_type_func=rotation_matrix_array_output)]
+fn get_rotation_matrix_array(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let matrices = rotation_matrices(inputs, &kwargs.fields)?;
+    list_to_array(&matrices, 16)
+}
 
-    let (rotation_x, rotation_y, rotation_z) = unpack_xyz(rotation_ca, false);
-    let rotation_w = rotation_ca
-        .field_by_name("w")
-        .expect("Unable to find `w` field for rotation!");
+fn rotation_matrices(inputs: &[Series], fields: &FieldMapping) -> PolarsResult<Series> {
+    let rotation_ca = &inputs[0];
+    let offset_ca = &inputs[1];
 
-    let (offset_x, offset_y, offset_z) = unpack_xyz(offset_ca, false);
+    let (rotation_x, rotation_y, rotation_z, rotation_w) = fields.unpack_xyzw(rotation_ca, 0)?;
+    let (offset_x, offset_y, offset_z) = fields.unpack_xyz(offset_ca, 1, false)?;
 
     let mut list_builder: ListPrimitiveChunkedBuilder<Float64Type> = ListPrimitiveChunkedBuilder::new("rotation_matrix", rotation_ca.len(), 16, DataType::Float64);
 
@@ -640,229 +1880,1559 @@ fn get_rotation_matrix(inputs: &[Series]) -> PolarsResult<Series> {
         offset_y_val,
         offset_z_val,
     ) in izip!(
-        rotation_x.f64().unwrap(),
-        rotation_y.f64().unwrap(),
-        rotation_z.f64().unwrap(),
-        rotation_w.f64().unwrap(),
-        offset_x.f64().unwrap(),
-        offset_y.f64().unwrap(),
-        offset_z.f64().unwrap()
+        rotation_x.f64()?,
+        rotation_y.f64()?,
+        rotation_z.f64()?,
+        rotation_w.f64()?,
+        offset_x.f64()?,
+        offset_y.f64()?,
+        offset_z.f64()?
     ) {
-        let rotation_vec = vec![rotation_x_val.unwrap(), rotation_y_val.unwrap(), rotation_z_val.unwrap(), rotation_w_val.unwrap()];
-        let offset_vec = vec![offset_x_val.unwrap(), offset_y_val.unwrap(), offset_z_val.unwrap()];
-
-        let rotation_matrix = get_rotation_matrix_elementwise(&rotation_vec, &offset_vec);
-        list_builder.append_slice(&rotation_matrix[..]);
+        match (
+            rotation_x_val,
+            rotation_y_val,
+            rotation_z_val,
+            rotation_w_val,
+            offset_x_val,
+            offset_y_val,
+            offset_z_val,
+        ) {
+            (
+                Some(rotation_x),
+                Some(rotation_y),
+                Some(rotation_z),
+                Some(rotation_w),
+                Some(offset_x),
+                Some(offset_y),
+                Some(offset_z),
+            ) => {
+                let rotation_vec = vec![rotation_x, rotation_y, rotation_z, rotation_w];
+                let offset_vec = vec![offset_x, offset_y, offset_z];
+
+                let rotation_matrix = get_rotation_matrix_elementwise(&rotation_vec, &offset_vec);
+                list_builder.append_slice(&rotation_matrix[..]);
+            }
+            _ => list_builder.append_null(),
+        }
     }
 
     let out = list_builder.finish();
     Ok(out.into_series())
 
 }
-//distance
+
+/// Reads one matrix per row out of List or Array values; rows with any missing value
+/// are null.
+fn matrix_rows(ser: &Series, widths: &[usize], shape: &str) -> PolarsResult<Vec<Option<Vec<f64>>>> {
+    let mut matrices = Vec::with_capacity(ser.len());
+    for (row, matrix_op) in float_lists(ser)?.into_iter().enumerate() {
+        let matrix = match matrix_op {
+            Some(matrix) => matrix,
+            None => {
+                matrices.push(None);
+                continue;
+            }
+        };
+        if !widths.contains(&matrix.len()) {
+            polars_bail!(
+                ShapeMismatch: "row {} of `{}` has {} values; expected {}",
+                row,
+                ser.name(),
+                matrix.len(),
+                shape
+            );
+        }
+        matrices.push(matrix.f64()?.into_iter().collect::<Option<Vec<f64>>>());
+    }
+    Ok(matrices)
+}
+
+/// Reads one transform matrix per row: 16 values, or 12 without the trailing
+/// `[0, 0, 0, 1]`, laid out as returned by `get_rotation_matrix`.
+fn transform_matrices(ser: &Series) -> PolarsResult<Vec<Option<Vec<f64>>>> {
+    matrix_rows(ser, &[12, 16], "a 4x4 or 3x4 matrix")
+}
+
+#[polars_expr(output_type_func=output_3d)]
+fn apply_matrix(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let coords_ca = &inputs[0];
+    let matrix_ser = broadcast_to_len(&inputs[1], coords_ca.len())?;
+
+    let (x_ser, y_ser, z_ser) = kwargs.fields.unpack_xyz(coords_ca, 0, false)?;
+    let matrices = transform_matrices(&matrix_ser)?;
+
+    let mut x_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("x", coords_ca.len());
+    let mut y_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("y", coords_ca.len());
+    let mut z_cb: PrimitiveChunkedBuilder<Float64Type> =
+        PrimitiveChunkedBuilder::new("z", coords_ca.len());
+
+    for (x_op, y_op, z_op, matrix_op) in izip!(x_ser.f64()?, y_ser.f64()?, z_ser.f64()?, &matrices)
+    {
+        match (x_op, y_op, z_op, matrix_op) {
+            (Some(x), Some(y), Some(z), Some(matrix)) => {
+                let (x_out, y_out, z_out) = apply_matrix_elementwise(vec![x, y, z], matrix);
+                x_cb.append_value(x_out);
+                y_cb.append_value(y_out);
+                z_cb.append_value(z_out);
+            }
+            _ => {
+                x_cb.append_null();
+                y_cb.append_null();
+                z_cb.append_null();
+            }
+        }
+    }
+
+    let ser_x = x_cb.finish().into_series();
+    let ser_y = y_cb.finish().into_series();
+    let ser_z = z_cb.finish().into_series();
+
+    let out_chunked = StructChunked::new("coords", &[ser_x, ser_y, ser_z])?;
+    Ok(out_chunked.into_series())
+}
+
+/// Dtype of poses: a `rotation` quaternion and an `offset`, as used by `map_to_ecef`.
+fn pose_dtype() -> DataType {
+    let rotation: Vec<Field> = QUAT_FIELDS
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64))
+        .collect();
+    let offset: Vec<Field> = ["x", "y", "z"]
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64))
+        .collect();
+    DataType::Struct(vec![
+        Field::new("rotation", DataType::Struct(rotation)),
+        Field::new("offset", DataType::Struct(offset)),
+    ])
+}
+
+fn pose_struct(
+    name: &str,
+    rotations: &[Option<[f64; 4]>],
+    offsets: &[Option<[f64; 3]>],
+) -> PolarsResult<Series> {
+    let out_chunked = StructChunked::new(
+        name,
+        &[
+            struct_from_rows("rotation", QUAT_FIELDS, rotations)?,
+            struct_from_rows("offset", ["x", "y", "z"], offsets)?,
+        ],
+    )?;
+    Ok(out_chunked.into_series())
+}
+
+/// Splits a pose struct into its `rotation` and `offset`.
+fn pose_parts(ser: &Series) -> PolarsResult<(Series, Series)> {
+    let ca = match ser.dtype() {
+        DataType::Struct(_) => ser.struct_()?,
+        dtype => polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected a pose struct {{rotation, offset}}",
+            ser.name(),
+            dtype
+        ),
+    };
+    let part = |name: &str| match ca.fields().iter().find(|field| field.name() == name) {
+        Some(field) => Ok(field.clone()),
+        None => polars_bail!(
+            ColumnNotFound: "pose `{}` has no field `{}`; expected struct {{rotation, offset}}",
+            ser.name(),
+            name
+        ),
+    };
+    Ok((part("rotation")?, part("offset")?))
+}
+
+fn quat_translation_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new("transform", pose_dtype()))
+}
+
+#[polars_expr(output_type_func=quat_translation_output)]
+fn matrix_to_quat_translation(inputs: &[Series]) -> PolarsResult<Series> {
+    let matrices = transform_matrices(&inputs[0])?;
+
+    let poses: Vec<Option<Pose>> = matrices
+        .iter()
+        .map(|matrix| {
+            matrix
+                .as_deref()
+                .map(matrix_to_quat_translation_elementwise)
+        })
+        .collect();
+    poses_struct("transform", poses)
+}
+
+//quaternions
+const QUAT_FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Zips `N` `Float64` columns into rows; a row with any null is null.
+fn rows_of<const N: usize>(columns: [&Series; N]) -> PolarsResult<Vec<Option<[f64; N]>>> {
+    let mut iters = Vec::with_capacity(N);
+    for ser in columns {
+        iters.push(ser.f64()?.into_iter());
+    }
+    let len = columns[0].len();
+    Ok((0..len)
+        .map(|_| {
+            let row: [Option<f64>; N] = std::array::from_fn(|i| iters[i].next().flatten());
+            row.iter()
+                .all(Option::is_some)
+                .then(|| row.map(Option::unwrap_or_default))
+        })
+        .collect())
+}
+
+/// Builds a struct of `Float64` fields out of rows; a null row is null in every field.
+fn struct_from_rows<const N: usize>(
+    name: &str,
+    field_names: [&str; N],
+    rows: &[Option<[f64; N]>],
+) -> PolarsResult<Series> {
+    let columns: Vec<Series> = field_names
+        .iter()
+        .enumerate()
+        .map(|(i, field_name)| {
+            Float64Chunked::from_iter_options(
+                field_name,
+                rows.iter().map(|row| row.map(|row| row[i])),
+            )
+            .into_series()
+        })
+        .collect();
+    Ok(StructChunked::new(name, &columns)?.into_series())
+}
+
+fn quat_rows(
+    fields: &FieldMapping,
+    ser: &Series,
+    input: usize,
+) -> PolarsResult<Vec<Option<[f64; 4]>>> {
+    let (x, y, z, w) = fields.unpack_xyzw(ser, input)?;
+    rows_of([&x, &y, &z, &w])
+}
+
+fn quat_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = QUAT_FIELDS
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64))
+        .collect();
+    Ok(Field::new("quat", DataType::Struct(v)))
+}
+
+fn apply_quat_unary(
+    inputs: &[Series],
+    fields: &FieldMapping,
+    func_elementwise: impl Fn([f64; 4]) -> Option<[f64; 4]>,
+) -> PolarsResult<Series> {
+    let quats = quat_rows(fields, &inputs[0], 0)?;
+    let out: Vec<Option<[f64; 4]>> = quats
+        .into_iter()
+        .map(|quat| quat.and_then(&func_elementwise))
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn quat_multiply(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let other_ser = broadcast_to_len(&inputs[1], inputs[0].len())?;
+    let quats = quat_rows(&kwargs.fields, &inputs[0], 0)?;
+    let others = quat_rows(&kwargs.fields, &other_ser, 1)?;
+
+    let out: Vec<Option<[f64; 4]>> = izip!(quats, others)
+        .map(|(quat, other)| {
+            quat.zip(other)
+                .map(|(q, o)| quat_multiply_elementwise(q, o))
+        })
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn quat_inverse(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    apply_quat_unary(inputs, &kwargs.fields, quat_inverse_elementwise)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn quat_normalize(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    apply_quat_unary(inputs, &kwargs.fields, quat_normalize_elementwise)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn quat_conjugate(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    apply_quat_unary(inputs, &kwargs.fields, |quat| {
+        Some(quat_conjugate_elementwise(quat))
+    })
+}
+
 #[polars_expr(output_type=Float64)]
-fn euclidean_2d(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca1: &StructChunked = inputs[0].struct_()?;
-    let ca2: &StructChunked = inputs[1].struct_()?;
+fn quat_angle_between(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let other_ser = broadcast_to_len(&inputs[1], inputs[0].len())?;
+    let quats = quat_rows(&kwargs.fields, &inputs[0], 0)?;
+    let others = quat_rows(&kwargs.fields, &other_ser, 1)?;
+
+    let out = Float64Chunked::from_iter_options(
+        "angle",
+        izip!(quats, others).map(|(quat, other)| {
+            quat.zip(other)
+                .map(|(q, o)| quat_angle_between_elementwise(q, o))
+        }),
+    );
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type_func=output_3d)]
+fn quat_rotate_vector(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let vector_ser = broadcast_to_len(&inputs[1], inputs[0].len())?;
+    let quats = quat_rows(&kwargs.fields, &inputs[0], 0)?;
+    let (x, y, z) = kwargs.fields.unpack_xyz(&vector_ser, 1, false)?;
+    let vectors = rows_of([&x, &y, &z])?;
+
+    let out: Vec<Option<[f64; 3]>> = izip!(quats, vectors)
+        .map(|(quat, vector)| {
+            quat.zip(vector)
+                .map(|(q, v)| quat_rotate_vector_elementwise(q, v))
+        })
+        .collect();
+    struct_from_rows("coords", ["x", "y", "z"], &out)
+}
+
+#[derive(Deserialize)]
+struct EulerKwargs {
+    order: String,
+    convention: String,
+    degrees: bool,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+#[derive(Deserialize)]
+struct AngleKwargs {
+    degrees: bool,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn euler_angles_to_quat(inputs: &[Series], kwargs: EulerKwargs) -> PolarsResult<Series> {
+    let order = match parse_euler_order(&kwargs.order) {
+        Some(order) => order,
+        None => polars_bail!(
+            ComputeError: "unknown rotation order `{}`, expected three distinct axes such as `XYZ`!",
+            kwargs.order
+        ),
+    };
+    let intrinsic = match kwargs.convention.as_str() {
+        "intrinsic" => true,
+        "extrinsic" => false,
+        convention => polars_bail!(
+            ComputeError: "unknown convention `{}`, expected `intrinsic` or `extrinsic`!",
+            convention
+        ),
+    };
 
-    let (x1, y1, _z1) = unpack_xyz(ca1, false);
-    let (x2, y2, _z2) = unpack_xyz(ca2, false);
+    let euler_fields = ["roll", "pitch", "yaw"];
+    let mut columns = Vec::with_capacity(3);
+    for field in euler_fields {
+        columns.push(kwargs.fields.field(&inputs[0], 0, field, &euler_fields)?);
+    }
+    let angles = rows_of([&columns[0], &columns[1], &columns[2]])?;
 
-    let iter = izip!(x1.f64()?, y1.f64()?, x2.f64()?, y2.f64()?)
+    let out: Vec<Option<[f64; 4]>> = angles
         .into_iter()
-        .map(
-            |(x1_op, y1_op, x2_op, y2_op)| match (x1_op, y1_op, x2_op, y2_op) {
-                (Some(x1), Some(y1), Some(x2), Some(y2)) => {
-                    euclidean_2d_elementwise(x1, y1, x2, y2)
-                }
-                _ => panic!("Unable to find euclidean distance!"),
-            },
+        .map(|angles| {
+            angles.map(|angles| {
+                let angles = angles.map(|angle| {
+                    if kwargs.degrees {
+                        angle.to_radians()
+                    } else {
+                        angle
+                    }
+                });
+                euler_angles_to_quat_elementwise(angles, order, intrinsic)
+            })
+        })
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
+}
+
+const AXIS_ANGLE_FIELDS: [&str; 4] = ["x", "y", "z", "angle"];
+
+#[polars_expr(output_type_func=quat_output)]
+fn axis_angle_to_quat(inputs: &[Series], kwargs: AngleKwargs) -> PolarsResult<Series> {
+    let mut columns = Vec::with_capacity(4);
+    for field in AXIS_ANGLE_FIELDS {
+        columns.push(
+            kwargs
+                .fields
+                .field(&inputs[0], 0, field, &AXIS_ANGLE_FIELDS)?,
         );
+    }
+    let rows = rows_of([&columns[0], &columns[1], &columns[2], &columns[3]])?;
 
-    let out_ca: ChunkedArray<Float64Type> =
-        iter.collect_ca_with_dtype("distance", DataType::Float64);
-    Ok(out_ca.into_series())
+    let out: Vec<Option<[f64; 4]>> = rows
+        .into_iter()
+        .map(|row| {
+            row.and_then(|[x, y, z, angle]| {
+                let angle = if kwargs.degrees {
+                    angle.to_radians()
+                } else {
+                    angle
+                };
+                axis_angle_to_quat_elementwise([x, y, z, angle])
+            })
+        })
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
 }
 
-#[polars_expr(output_type=Float64)]
-fn euclidean_3d(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca1: &StructChunked = inputs[0].struct_()?;
-    let ca2: &StructChunked = inputs[1].struct_()?;
-
-    let (x1, y1, z1) = unpack_xyz(ca1, false);
-    let (x2, y2, z2) = unpack_xyz(ca2, false);
-
-    let iter = izip!(
-        x1.f64()?,
-        y1.f64()?,
-        z1.f64()?,
-        x2.f64()?,
-        y2.f64()?,
-        z2.f64()?
-    )
-    .into_iter()
-    .map(|(x1_op, y1_op, z1_op, x2_op, y2_op, z2_op)| {
-        match (x1_op, y1_op, z1_op, x2_op, y2_op, z2_op) {
-            (Some(x1), Some(y1), Some(z1), Some(x2), Some(y2), Some(z2)) => {
-                euclidean_3d_elementwise(x1, y1, z1, x2, y2, z2)
+fn axis_angle_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = AXIS_ANGLE_FIELDS
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64))
+        .collect();
+    Ok(Field::new("axis_angle", DataType::Struct(v)))
+}
+
+#[polars_expr(output_type_func=axis_angle_output)]
+fn quat_to_axis_angle(inputs: &[Series], kwargs: AngleKwargs) -> PolarsResult<Series> {
+    let quats = quat_rows(&kwargs.fields, &inputs[0], 0)?;
+
+    let out: Vec<Option<[f64; 4]>> = quats
+        .into_iter()
+        .map(|quat| {
+            quat.map(|quat| {
+                let [x, y, z, angle] = quat_to_axis_angle_elementwise(quat);
+                let angle = if kwargs.degrees {
+                    angle.to_degrees()
+                } else {
+                    angle
+                };
+                [x, y, z, angle]
+            })
+        })
+        .collect();
+    struct_from_rows("axis_angle", AXIS_ANGLE_FIELDS, &out)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn rotation_matrix_to_quat(inputs: &[Series]) -> PolarsResult<Series> {
+    let matrices = matrix_rows(&inputs[0], &[9], "a 3x3 matrix")?;
+
+    let out: Vec<Option<[f64; 4]>> = matrices
+        .iter()
+        .map(|matrix| matrix.as_deref().map(rotation_matrix_to_quat_elementwise))
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
+}
+
+fn quat_rotation_matrices(inputs: &[Series], fields: &FieldMapping) -> PolarsResult<Series> {
+    let quats = quat_rows(fields, &inputs[0], 0)?;
+
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(quats.len() * 9);
+    for quat in quats {
+        match quat {
+            Some(quat) => values.extend(quat_to_rotation_matrix_elementwise(quat).map(Some)),
+            None => values.extend(std::iter::repeat_n(None, 9)),
+        }
+    }
+    Ok(coordinates_to_array("rotation_matrix", 9, values))
+}
+
+fn rotation_matrix_3x3_output(_: &[Field]) -> PolarsResult<Field> {
+    let field = Field::new(
+        "rotation_matrix",
+        DataType::List(Box::new(DataType::Float64)),
+    );
+    Ok(field)
+}
+
+#[polars_expr(output_type_func=rotation_matrix_3x3_output)]
+fn quat_to_rotation_matrix(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let matrices = quat_rotation_matrices(inputs, &kwargs.fields)?;
+    matrices.cast(&DataType::List(Box::new(DataType::Float64)))
+}
+
+fn rotation_matrix_3x3_array_output(_: &[Field]) -> PolarsResult<Field> {
+    let field = Field::new(
+        "rotation_matrix",
+        DataType::Array(Box::new(DataType::Float64), 9),
+    );
+    Ok(field)
+}
+
+#[polars_expr(output_type_func=rotation_matrix_3x3_array_output)]
+fn quat_to_rotation_matrix_array(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    quat_rotation_matrices(inputs, &kwargs.fields)
+}
+
+/// Reads the interpolation parameter, broadcast to `len`, as `Float64`.
+fn interpolation_parameter(ser: &Series, len: usize) -> PolarsResult<Series> {
+    let t = broadcast_to_len(ser, len)?;
+    normalize_coordinates(&t)
+}
+
+#[polars_expr(output_type_func=quat_output)]
+fn quat_slerp(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let len = inputs[0].len();
+    let other_ser = broadcast_to_len(&inputs[1], len)?;
+    let t_ser = interpolation_parameter(&inputs[2], len)?;
+    let quats = quat_rows(&kwargs.fields, &inputs[0], 0)?;
+    let others = quat_rows(&kwargs.fields, &other_ser, 1)?;
+
+    let out: Vec<Option<[f64; 4]>> = izip!(quats, others, t_ser.f64()?)
+        .map(|(quat, other, t)| match (quat, other, t) {
+            (Some(quat), Some(other), Some(t)) => Some(quat_slerp_elementwise(quat, other, t)),
+            _ => None,
+        })
+        .collect();
+    struct_from_rows("quat", QUAT_FIELDS, &out)
+}
+
+fn pose_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new("pose", pose_dtype()))
+}
+
+/// Interpolates poses: the offset linearly and the rotation with SLERP. Struct inputs
+/// are numbered pose offset, pose rotation, other offset, other rotation.
+#[polars_expr(output_type_func=pose_output)]
+fn interpolate_pose(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    interpolate_poses(inputs, &kwargs.fields)
+}
+
+fn interpolate_poses(inputs: &[Series], fields: &FieldMapping) -> PolarsResult<Series> {
+    let len = inputs[0].len();
+    let (rotation_ser, offset_ser) = pose_parts(&inputs[0])?;
+    let other_offset_ser = broadcast_to_len(&inputs[1], len)?;
+    let other_rotation_ser = broadcast_to_len(&inputs[2], len)?;
+    let t_ser = interpolation_parameter(&inputs[3], len)?;
+
+    let (x, y, z) = fields.unpack_xyz(&offset_ser, 0, false)?;
+    let offsets = rows_of([&x, &y, &z])?;
+    let rotations = quat_rows(fields, &rotation_ser, 1)?;
+    let (x, y, z) = fields.unpack_xyz(&other_offset_ser, 2, false)?;
+    let other_offsets = rows_of([&x, &y, &z])?;
+    let other_rotations = quat_rows(fields, &other_rotation_ser, 3)?;
+
+    let mut rotations_out = Vec::with_capacity(len);
+    let mut offsets_out = Vec::with_capacity(len);
+    for (offset, rotation, other_offset, other_rotation, t) in izip!(
+        offsets,
+        rotations,
+        other_offsets,
+        other_rotations,
+        t_ser.f64()?
+    ) {
+        match (offset, rotation, other_offset, other_rotation, t) {
+            (Some(offset), Some(rotation), Some(other_offset), Some(other_rotation), Some(t)) => {
+                let (x, y, z) =
+                    interpolate_linear_elementwise(offset.to_vec(), other_offset.to_vec(), 1.0 - t);
+                offsets_out.push(Some([x, y, z]));
+                rotations_out.push(Some(quat_slerp_elementwise(rotation, other_rotation, t)));
+            }
+            _ => {
+                offsets_out.push(None);
+                rotations_out.push(None);
             }
-            _ => panic!("Unable to find euclidean distance!"),
         }
-    });
+    }
+    pose_struct("pose", &rotations_out, &offsets_out)
+}
 
-    let out_ca: ChunkedArray<Float64Type> =
-        iter.collect_ca_with_dtype("distance", DataType::Float64);
-    Ok(out_ca.into_series())
+#[derive(Deserialize)]
+struct PoseAtKwargs {
+    /// In microseconds for temporal timestamps, in their own units otherwise.
+    max_gap: Option<f64>,
+    extrapolate: String,
+    #[serde(default)]
+    fields: FieldMapping,
 }
 
-#[polars_expr(output_type=Float64)]
-fn cosine_similarity_2d(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca1: &StructChunked = inputs[0].struct_()?;
-    let ca2: &StructChunked = inputs[1].struct_()?;
+/// Timestamps as `Float64` in their physical unit, plus the number of such units in a
+/// microsecond (`None` for plain numbers, whose gaps are given in their own units).
+fn timestamps_as_f64(ser: &Series) -> PolarsResult<(Series, Option<f64>)> {
+    let units_per_us = match ser.dtype() {
+        DataType::Datetime(time_unit, _) | DataType::Duration(time_unit) => match time_unit {
+            TimeUnit::Nanoseconds => Some(1000.0),
+            TimeUnit::Microseconds => Some(1.0),
+            TimeUnit::Milliseconds => Some(1e-3),
+        },
+        DataType::Date => Some(1.0 / 86_400_000_000.0),
+        dtype if is_coordinate_dtype(dtype) => None,
+        dtype => polars_bail!(
+            SchemaMismatch: "`{}` has dtype {}; expected numeric or temporal timestamps",
+            ser.name(),
+            dtype
+        ),
+    };
+    let physical = ser.to_physical_repr().cast(&DataType::Float64)?;
+    Ok((physical, units_per_us))
+}
 
-    let (x1, y1, _z1) = unpack_xyz(ca1, false);
-    let (x2, y2, _z2) = unpack_xyz(ca2, false);
+/// Resamples poses sampled at ascending `timestamps` at the `query` timestamps; the
+/// result has one pose per query.
+#[polars_expr(output_type_func=pose_output)]
+fn interpolate_pose_at(inputs: &[Series], kwargs: PoseAtKwargs) -> PolarsResult<Series> {
+    poses_at(inputs, &kwargs)
+}
 
-    let iter = izip!(x1.f64()?, y1.f64()?, x2.f64()?, y2.f64()?)
-        .into_iter()
-        .map(
-            |(x1_op, y1_op, x2_op, y2_op)| match (x1_op, y1_op, x2_op, y2_op) {
-                (Some(x1), Some(y1), Some(x2), Some(y2)) => {
-                    cosine_similarity_2d_elementwise(x1, y1, x2, y2)
-                }
-                _ => panic!("Unable to find cosine similarity!"),
-            },
+fn poses_at(inputs: &[Series], kwargs: &PoseAtKwargs) -> PolarsResult<Series> {
+    let extrapolation = match Extrapolation::from_name(&kwargs.extrapolate) {
+        Some(extrapolation) => extrapolation,
+        None => polars_bail!(
+            ComputeError: "unknown extrapolation `{}`, expected one of `none`, `nearest`, `linear`!",
+            kwargs.extrapolate
+        ),
+    };
+    if inputs[1].len() != inputs[0].len() {
+        polars_bail!(
+            ShapeMismatch: "got {} poses but {} pose timestamps", inputs[0].len(), inputs[1].len()
         );
+    }
+    let (times_ser, units_per_us) = timestamps_as_f64(&inputs[1])?;
+    let (query_ser, _) = timestamps_as_f64(&inputs[2].cast(inputs[1].dtype())?)?;
+    let max_gap = kwargs
+        .max_gap
+        .map(|max_gap| max_gap * units_per_us.unwrap_or(1.0));
+
+    let (rotation_ser, offset_ser) = pose_parts(&inputs[0])?;
+    let (x, y, z) = kwargs.fields.unpack_xyz(&offset_ser, 0, false)?;
+    let offsets = rows_of([&x, &y, &z])?;
+    let rotations = quat_rows(&kwargs.fields, &rotation_ser, 1)?;
+
+    // Samples with a null timestamp or pose component are skipped.
+    let mut times = Vec::with_capacity(offsets.len());
+    let mut samples = Vec::with_capacity(offsets.len());
+    for (time, offset, rotation) in izip!(times_ser.f64()?, offsets, rotations) {
+        if let (Some(time), Some(offset), Some(rotation)) = (time, offset, rotation) {
+            if times.last().is_some_and(|&last| time < last) {
+                polars_bail!(ComputeError: "pose timestamps must be sorted in ascending order!");
+            }
+            times.push(time);
+            samples.push((offset, rotation));
+        }
+    }
+
+    let mut rotations_out = Vec::with_capacity(query_ser.len());
+    let mut offsets_out = Vec::with_capacity(query_ser.len());
+    for query in query_ser.f64()? {
+        let bracket =
+            query.and_then(|query| bracket_samples(&times, query, max_gap, extrapolation));
+        match bracket {
+            Some((i0, i1, t)) => {
+                let (offset0, rotation0) = samples[i0];
+                let (offset1, rotation1) = samples[i1];
+                let (x, y, z) =
+                    interpolate_linear_elementwise(offset1.to_vec(), offset0.to_vec(), t);
+                offsets_out.push(Some([x, y, z]));
+                rotations_out.push(Some(quat_slerp_elementwise(rotation0, rotation1, t)));
+            }
+            None => {
+                offsets_out.push(None);
+                rotations_out.push(None);
+            }
+        }
+    }
+    pose_struct("pose", &rotations_out, &offsets_out)
+}
+
+//poses
+/// Reads a pose struct; its offset takes the struct input index `input`, its rotation
+/// the next one.
+fn pose_rows(fields: &FieldMapping, ser: &Series, input: usize) -> PolarsResult<Vec<Option<Pose>>> {
+    let (rotation_ser, offset_ser) = pose_parts(ser)?;
+    let (x, y, z) = fields.unpack_xyz(&offset_ser, input, false)?;
+    let offsets = rows_of([&x, &y, &z])?;
+    let rotations = quat_rows(fields, &rotation_ser, input + 1)?;
+    Ok(izip!(rotations, offsets)
+        .map(|(rotation, offset)| rotation.zip(offset))
+        .collect())
+}
+
+fn poses_struct(name: &str, poses: Vec<Option<Pose>>) -> PolarsResult<Series> {
+    let (rotations, offsets): (Vec<_>, Vec<_>) = poses
+        .into_iter()
+        .map(|pose| (pose.map(|pose| pose.0), pose.map(|pose| pose.1)))
+        .unzip();
+    pose_struct(name, &rotations, &offsets)
+}
+
+fn apply_pose_binary(
+    inputs: &[Series],
+    fields: &FieldMapping,
+    func_elementwise: impl Fn(Pose, Pose) -> Pose,
+) -> PolarsResult<Series> {
+    let other_ser = broadcast_to_len(&inputs[1], inputs[0].len())?;
+    let poses = pose_rows(fields, &inputs[0], 0)?;
+    let others = pose_rows(fields, &other_ser, 2)?;
+
+    let out: Vec<Option<Pose>> = izip!(poses, others)
+        .map(|(pose, other)| pose.zip(other).map(|(p, o)| func_elementwise(p, o)))
+        .collect();
+    poses_struct("pose", out)
+}
+
+#[polars_expr(output_type_func=pose_output)]
+fn pose_compose(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    apply_pose_binary(inputs, &kwargs.fields, pose_compose_elementwise)
+}
+
+#[polars_expr(output_type_func=pose_output)]
+fn pose_relative_to(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    apply_pose_binary(inputs, &kwargs.fields, pose_relative_to_elementwise)
+}
+
+#[polars_expr(output_type_func=pose_output)]
+fn pose_inverse(inputs: &[Series], kwargs: FieldsKwargs) -> PolarsResult<Series> {
+    let poses = pose_rows(&kwargs.fields, &inputs[0], 0)?;
+    let out: Vec<Option<Pose>> = poses
+        .into_iter()
+        .map(|pose| pose.map(pose_inverse_elementwise))
+        .collect();
+    poses_struct("pose", out)
+}
+
+//distance
+#[derive(Deserialize)]
+struct DistanceKwargs {
+    null_policy: String,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+fn parse_null_policy(name: &str) -> PolarsResult<NullPolicy> {
+    match NullPolicy::from_name(name) {
+        Some(null_policy) => Ok(null_policy),
+        None => polars_bail!(
+            ComputeError: "unknown null policy `{}`, expected one of `propagate`, `zero`, `raise`!",
+            name
+        ),
+    }
+}
+
+/// Applies a kernel row by row under `null_policy`; the first half of `columns` holds
+/// the coordinates of the first point, the second half the ones of the other point.
+fn apply_null_policy<const N: usize, T: Default>(
+    columns: [&Series; N],
+    null_policy: NullPolicy,
+    func_elementwise: impl Fn([f64; N]) -> T,
+) -> PolarsResult<Vec<Option<T>>> {
+    let len = columns[0].len();
+    let mut iters = Vec::with_capacity(N);
+    for ser in columns {
+        iters.push(ser.f64()?.into_iter());
+    }
+
+    let mut out: Vec<Option<T>> = Vec::with_capacity(len);
+    for row in 0..len {
+        let coords: [Option<f64>; N] = std::array::from_fn(|i| iters[i].next().flatten());
+        match null_policy.apply(coords, &func_elementwise) {
+            Ok(value) => out.push(value),
+            Err(position) => polars_bail!(
+                ComputeError: "null value in field `{}` of the {} point at row {}!",
+                columns[position].name(),
+                if position < N / 2 { "first" } else { "other" },
+                row
+            ),
+        }
+    }
+    Ok(out)
+}
 
-    let out_ca: ChunkedArray<Float64Type> =
-        iter.collect_ca_with_dtype("cosine_similarity", DataType::Float64);
+fn apply_distance_kernel<const N: usize>(
+    columns: [&Series; N],
+    null_policy: NullPolicy,
+    name: &str,
+    func_elementwise: impl Fn([f64; N]) -> f64,
+) -> PolarsResult<Series> {
+    let out_ca: Float64Chunked = apply_null_policy(columns, null_policy, func_elementwise)?
+        .into_iter()
+        .collect_ca(name);
     Ok(out_ca.into_series())
 }
 
 #[polars_expr(output_type=Float64)]
-fn cosine_similarity_3d(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca1: &StructChunked = inputs[0].struct_()?;
-    let ca2: &StructChunked = inputs[1].struct_()?;
-
-    let (x1, y1, z1) = unpack_xyz(ca1, false);
-    let (x2, y2, z2) = unpack_xyz(ca2, false);
-
-    let iter = izip!(
-        x1.f64()?,
-        y1.f64()?,
-        z1.f64()?,
-        x2.f64()?,
-        y2.f64()?,
-        z2.f64()?,
+fn euclidean_2d(inputs: &[Series], kwargs: DistanceKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    let (x1, y1) = kwargs.fields.unpack_xy(ca1, 0)?;
+    let (x2, y2) = kwargs.fields.unpack_xy(ca2, 1)?;
+
+    apply_distance_kernel(
+        [&x1, &y1, &x2, &y2],
+        null_policy,
+        "distance",
+        |[x1, y1, x2, y2]| euclidean_2d_elementwise(x1, y1, x2, y2),
     )
-    .into_iter()
-    .map(|(x1_op, y1_op, z1_op, x2_op, y2_op, z2_op)| {
-        match (x1_op, y1_op, z1_op, x2_op, y2_op, z2_op) {
-            (Some(x1), Some(y1), Some(z1), Some(x2), Some(y2), Some(z2)) => {
-                cosine_similarity_3d_elementwise(x1, y1, z1, x2, y2, z2)
-            }
-            _ => panic!("Unable to find cosine similarity!"),
-        }
-    });
+}
 
-    let out_ca: ChunkedArray<Float64Type> =
-        iter.collect_ca_with_dtype("cosine_similarity", DataType::Float64);
-    Ok(out_ca.into_series())
+#[polars_expr(output_type=Float64)]
+fn euclidean_3d(inputs: &[Series], kwargs: DistanceKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    let (x1, y1, z1) = kwargs.fields.unpack_xyz(ca1, 0, false)?;
+    let (x2, y2, z2) = kwargs.fields.unpack_xyz(ca2, 1, false)?;
+
+    apply_distance_kernel(
+        [&x1, &y1, &z1, &x2, &y2, &z2],
+        null_policy,
+        "distance",
+        |[x1, y1, z1, x2, y2, z2]| euclidean_3d_elementwise(x1, y1, z1, x2, y2, z2),
+    )
 }
 
-#[polars_expr(output_type = Float64)]
-fn bboxes_2d(inputs: &[Series]) -> PolarsResult<Series> {
-    let box_a = inputs[0].struct_()?;
-    let box_b = inputs[1].struct_()?;
+#[polars_expr(output_type=Float64)]
+fn cosine_similarity_2d(inputs: &[Series], kwargs: DistanceKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    let (x1, y1) = kwargs.fields.unpack_xy(ca1, 0)?;
+    let (x2, y2) = kwargs.fields.unpack_xy(ca2, 1)?;
+
+    apply_distance_kernel(
+        [&x1, &y1, &x2, &y2],
+        null_policy,
+        "cosine_similarity",
+        |[x1, y1, x2, y2]| cosine_similarity_2d_elementwise(x1, y1, x2, y2),
+    )
+}
+
+#[polars_expr(output_type=Float64)]
+fn cosine_similarity_3d(inputs: &[Series], kwargs: DistanceKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    let (x1, y1, z1) = kwargs.fields.unpack_xyz(ca1, 0, false)?;
+    let (x2, y2, z2) = kwargs.fields.unpack_xyz(ca2, 1, false)?;
+
+    apply_distance_kernel(
+        [&x1, &y1, &z1, &x2, &y2, &z2],
+        null_policy,
+        "cosine_similarity",
+        |[x1, y1, z1, x2, y2, z2]| cosine_similarity_3d_elementwise(x1, y1, z1, x2, y2, z2),
+    )
+}
 
+fn bearing_output(_: &[Field]) -> PolarsResult<Field> {
+    let v: Vec<Field> = vec![
+        Field::new("initial", DataType::Float64),
+        Field::new("final", DataType::Float64),
+    ];
+    Ok(Field::new("bearing", DataType::Struct(v)))
+}
 
-    let out_chunked = get_distance_between_bboxes_2d(&box_a, &box_b);
+#[derive(Deserialize)]
+struct BearingKwargs {
+    ellipsoid: String,
+    semi_major_axis: Option<f64>,
+    flattening: Option<f64>,
+    null_policy: String,
+    #[serde(default)]
+    fields: FieldMapping,
+}
 
-    Ok(out_chunked?.into_series())
+#[polars_expr(output_type_func=bearing_output)]
+fn bearing(inputs: &[Series], kwargs: BearingKwargs) -> PolarsResult<Series> {
+    let ellipsoid =
+        parse_reference_ellipsoid(&kwargs.ellipsoid, kwargs.semi_major_axis, kwargs.flattening)?;
+    let geodesic = Geodesic::new(ellipsoid.semi_major_axis, ellipsoid.flattening);
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+    geodesic_bearings(
+        &inputs[0],
+        &inputs[1],
+        &kwargs.fields,
+        &geodesic,
+        null_policy,
+    )
+}
+
+fn geodesic_bearings(
+    ca1: &Series,
+    ca2: &Series,
+    fields: &FieldMapping,
+    geodesic: &Geodesic,
+    null_policy: NullPolicy,
+) -> PolarsResult<Series> {
+    let (lon1, lat1) = fields.unpack_lonlat(ca1, 0)?;
+    let (lon2, lat2) = fields.unpack_lonlat(ca2, 1)?;
+
+    let bearings = apply_null_policy(
+        [&lon1, &lat1, &lon2, &lat2],
+        null_policy,
+        |[lon1, lat1, lon2, lat2]| geodesic_bearing_elementwise(lon1, lat1, lon2, lat2, geodesic),
+    )?;
+    let (initial, last): (Float64Chunked, Float64Chunked) = (
+        bearings
+            .iter()
+            .map(|b| b.map(|(initial, _)| initial))
+            .collect_ca("initial"),
+        bearings
+            .iter()
+            .map(|b| b.map(|(_, last)| last))
+            .collect_ca("final"),
+    );
+
+    let out_chunked = StructChunked::new("bearing", &[initial.into_series(), last.into_series()])?;
+    Ok(out_chunked.into_series())
+}
+
+fn parse_distance_unit(unit: &str) -> PolarsResult<f64> {
+    match meters_per_unit(unit) {
+        Some(meters) => Ok(meters),
+        None => polars_bail!(ComputeError: "unknown distance unit `{}`!", unit),
+    }
+}
+
+fn apply_lonlat_distance(
+    ca1: &Series,
+    ca2: &Series,
+    fields: &FieldMapping,
+    null_policy: NullPolicy,
+    func_elementwise: impl Fn(f64, f64, f64, f64) -> f64,
+) -> PolarsResult<Series> {
+    let (lon1, lat1) = fields.unpack_lonlat(ca1, 0)?;
+    let (lon2, lat2) = fields.unpack_lonlat(ca2, 1)?;
+
+    apply_distance_kernel(
+        [&lon1, &lat1, &lon2, &lat2],
+        null_policy,
+        "distance",
+        |[lon1, lat1, lon2, lat2]| func_elementwise(lon1, lat1, lon2, lat2),
+    )
+}
+
+#[derive(Deserialize)]
+struct HaversineKwargs {
+    radius: Option<f64>,
+    unit: String,
+    null_policy: String,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+#[polars_expr(output_type=Float64)]
+fn haversine(inputs: &[Series], kwargs: HaversineKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let meters_per_unit = parse_distance_unit(&kwargs.unit)?;
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+    let radius = kwargs.radius.unwrap_or(EARTH_MEAN_RADIUS);
+
+    apply_lonlat_distance(
+        ca1,
+        ca2,
+        &kwargs.fields,
+        null_policy,
+        |lon1, lat1, lon2, lat2| {
+            haversine_elementwise(lon1, lat1, lon2, lat2, radius) / meters_per_unit
+        },
+    )
+}
+
+#[derive(Deserialize)]
+struct GeodesicKwargs {
+    ellipsoid: String,
+    semi_major_axis: Option<f64>,
+    flattening: Option<f64>,
+    unit: String,
+    null_policy: String,
+    #[serde(default)]
+    fields: FieldMapping,
+}
+
+impl GeodesicKwargs {
+    fn geodesic(&self) -> PolarsResult<Geodesic> {
+        let ellipsoid =
+            parse_reference_ellipsoid(&self.ellipsoid, self.semi_major_axis, self.flattening)?;
+        Ok(Geodesic::new(
+            ellipsoid.semi_major_axis,
+            ellipsoid.flattening,
+        ))
+    }
+}
+
+#[polars_expr(output_type=Float64)]
+fn geodesic(inputs: &[Series], kwargs: GeodesicKwargs) -> PolarsResult<Series> {
+    let ca1 = &inputs[0];
+    let ca2 = &inputs[1];
+    let geodesic = kwargs.geodesic()?;
+    let meters_per_unit = parse_distance_unit(&kwargs.unit)?;
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    apply_lonlat_distance(
+        ca1,
+        ca2,
+        &kwargs.fields,
+        null_policy,
+        |lon1, lat1, lon2, lat2| {
+            geodesic_elementwise(lon1, lat1, lon2, lat2, &geodesic) / meters_per_unit
+        },
+    )
+}
+
+#[polars_expr(output_type = Float64)]
+fn bboxes_2d(inputs: &[Series], kwargs: DistanceKwargs) -> PolarsResult<Series> {
+    let box_a = &inputs[0];
+    let box_b = &inputs[1];
+    let null_policy = parse_null_policy(&kwargs.null_policy)?;
+
+    get_distance_between_bboxes_2d(box_a, box_b, &kwargs.fields, null_policy)
 }
 
 fn get_distance_between_bboxes_2d(
-    box1: &StructChunked,
-    box2: &StructChunked,
+    box1: &Series,
+    box2: &Series,
+    fields_mapping: &FieldMapping,
+    null_policy: NullPolicy,
 ) -> Result<Series, PolarsError> {
+    let fields = [
+        "bbox_1_x", "bbox_1_y", "bbox_2_x", "bbox_2_y", "bbox_3_x", "bbox_3_y", "bbox_4_x",
+        "bbox_4_y",
+    ];
+    let mut columns: Vec<Series> = Vec::with_capacity(2 * fields.len());
+    for (input, bbox) in [box1, box2].into_iter().enumerate() {
+        for field in fields {
+            columns.push(fields_mapping.field(bbox, input, field, &fields)?);
+        }
+    }
+    let columns: [&Series; 16] = std::array::from_fn(|i| &columns[i]);
 
-    let bbox1_1_x_ser = &box1.field_by_name("bbox_1_x")?;
-    let bbox1_1_y_ser = &box1.field_by_name("bbox_1_y")?;
-    let bbox1_2_x_ser = &box1.field_by_name("bbox_2_x")?;
-    let bbox1_2_y_ser = &box1.field_by_name("bbox_2_y")?;
-    let bbox1_3_x_ser = &box1.field_by_name("bbox_3_x")?;
-    let bbox1_3_y_ser = &box1.field_by_name("bbox_3_y")?;
-    let bbox1_4_x_ser = &box1.field_by_name("bbox_4_x")?;
-    let bbox1_4_y_ser = &box1.field_by_name("bbox_4_y")?;
+    apply_distance_kernel(columns, null_policy, "distance", |coords| {
+        let bbox1 = std::array::from_fn(|i| PointCoords {
+            x: coords[2 * i],
+            y: coords[2 * i + 1],
+        });
+        let bbox2 = std::array::from_fn(|i| PointCoords {
+            x: coords[8 + 2 * i],
+            y: coords[8 + 2 * i + 1],
+        });
+        bboxes_2d_elementwise(bbox1, bbox2)
+    })
+}
 
-    let bbox2_1_x_ser = &box2.field_by_name("bbox_1_x")?;
-    let bbox2_1_y_ser = &box2.field_by_name("bbox_1_y")?;
-    let bbox2_2_x_ser = &box2.field_by_name("bbox_2_x")?;
-    let bbox2_2_y_ser = &box2.field_by_name("bbox_2_y")?;
-    let bbox2_3_x_ser = &box2.field_by_name("bbox_3_x")?;
-    let bbox2_3_y_ser = &box2.field_by_name("bbox_3_y")?;
-    let bbox2_4_x_ser = &box2.field_by_name("bbox_4_x")?;
-    let bbox2_4_y_ser = &box2.field_by_name("bbox_4_y")?;
+#[cfg(test)]
+mod expressions_tests {
+    use crate::coord_transforms::{lla_to_utm_full_elementwise, utm_to_lla_elementwise};
+    use crate::distance::{euclidean_2d_elementwise, NullPolicy};
+    use crate::ellipsoid::ReferenceEllipsoid;
+    use crate::expressions::{
+        apply_distance_kernel, coordinates_to_array, geodesic_bearings, interpolate_points,
+        interpolate_poses, parse_utm_hemisphere, pose_struct, poses_at, quat_rotation_matrices,
+        transform_matrices, utm_zone_numbers, FieldMapping, PoseAtKwargs,
+        TransformInterpolateKwargs,
+    };
+    use geographiclib_rs::Geodesic;
+    use polars::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_unpack_xyz_errors() {
+        let lon = Series::new("lon", [30.0]);
+        let alt = Series::new("alt", [10.0]);
+        let coords = StructChunked::new("coords", &[lon.clone(), alt])
+            .unwrap()
+            .into_series();
+
+        let err = FieldMapping::default()
+            .unpack_xyz(&coords, 0, true)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+        assert!(err
+            .to_string()
+            .contains("field `lat` not found in struct `coords`"));
+
+        let lat = Series::new("lat", ["60.0"]);
+        let alt = Series::new("alt", [10.0]);
+        let coords = StructChunked::new("coords", &[lon, lat, alt])
+            .unwrap()
+            .into_series();
+
+        let err = FieldMapping::default()
+            .unpack_xyz(&coords, 0, true)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+        assert!(err
+            .to_string()
+            .contains("expected struct {lon: numeric, lat: numeric, alt: numeric}"));
+    }
 
+    #[test]
+    fn test_unpack_xyz_float32() {
+        let coords = StructChunked::new(
+            "coords",
+            &[
+                Series::new("lon", [30.5f32]),
+                Series::new("lat", [60.25f32]),
+                Series::new("alt", [10.0f32]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+
+        let (lon, lat, alt) = FieldMapping::default()
+            .unpack_xyz(&coords, 0, true)
+            .unwrap();
+        assert_eq!(lon.f64().unwrap().get(0), Some(30.5));
+        assert_eq!(lat.f64().unwrap().get(0), Some(60.25));
+        assert_eq!(alt.f64().unwrap().get(0), Some(10.0));
+    }
 
-    let mut distances: PrimitiveChunkedBuilder<Float64Type> =
-        PrimitiveChunkedBuilder::new("distance".into(), box1.len());
+    #[test]
+    fn test_field_mapping() {
+        let coords = StructChunked::new(
+            "coords",
+            &[
+                Series::new("east", [1.0]),
+                Series::new("north", [2.0]),
+                Series::new("up", [3.0]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        let quat = StructChunked::new(
+            "quat",
+            &[
+                Series::new("qx", [0.0]),
+                Series::new("qy", [0.0]),
+                Series::new("qz", [0.0]),
+                Series::new("qw", [1.0f32]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+
+        let enu: HashMap<String, String> = [("x", "east"), ("y", "north"), ("z", "up")]
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .into();
+        let quat_fields: HashMap<String, String> =
+            [("x", "qx"), ("y", "qy"), ("z", "qz"), ("w", "qw")]
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .into();
+
+        let shared = FieldMapping(vec![enu.clone()]);
+        let (_, y, _) = shared.unpack_xyz(&coords, 1, false).unwrap();
+        assert_eq!(y.name(), "north");
+
+        let per_input = FieldMapping(vec![enu, quat_fields]);
+        let (_, _, z) = per_input.unpack_xyz(&coords, 0, false).unwrap();
+        let (_, _, _, w) = per_input.unpack_xyzw(&quat, 1).unwrap();
+        assert_eq!(z.f64().unwrap().get(0), Some(3.0));
+        assert_eq!(w.f64().unwrap().get(0), Some(1.0));
+
+        let err = per_input.unpack_xyzw(&coords, 1).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected struct {qx: numeric, qy: numeric, qz: numeric, qw: numeric}"));
+    }
 
-    for (
-        bbox1_1_x,
-        bbox1_1_y,
-        bbox1_2_x,
-        bbox1_2_y,
-        bbox1_3_x,
-        bbox1_3_y,
-        bbox1_4_x,
-        bbox1_4_y,
-        bbox2_1_x,
-        bbox2_1_y,
-        bbox2_2_x,
-        bbox2_2_y,
-        bbox2_3_x,
-        bbox2_3_y,
-        bbox2_4_x,
-        bbox2_4_y
-    ) in izip!(
-        bbox1_1_x_ser.f64().unwrap(),
-        bbox1_1_y_ser.f64().unwrap(),
-        bbox1_2_x_ser.f64().unwrap(),
-        bbox1_2_y_ser.f64().unwrap(),
-        bbox1_3_x_ser.f64().unwrap(),
-        bbox1_3_y_ser.f64().unwrap(),
-        bbox1_4_x_ser.f64().unwrap(),
-        bbox1_4_y_ser.f64().unwrap(),
-        bbox2_1_x_ser.f64().unwrap(),
-        bbox2_1_y_ser.f64().unwrap(),
-        bbox2_2_x_ser.f64().unwrap(),
-        bbox2_2_y_ser.f64().unwrap(),
-        bbox2_3_x_ser.f64().unwrap(),
-        bbox2_3_y_ser.f64().unwrap(),
-        bbox2_4_x_ser.f64().unwrap(),
-        bbox2_4_y_ser.f64().unwrap()
-    ) {
-        let bbox1 = [
-            PointCoords { x: bbox1_1_x.unwrap(), y: bbox1_1_y.unwrap()},
-            PointCoords { x: bbox1_2_x.unwrap(), y: bbox1_2_y.unwrap()},
-            PointCoords { x: bbox1_3_x.unwrap(), y: bbox1_3_y.unwrap()},
-            PointCoords { x: bbox1_4_x.unwrap(), y: bbox1_4_y.unwrap()},
-        ];
+    #[test]
+    fn test_numeric_coordinates() {
+        let decimal = Series::new("z", [1.5f64])
+            .cast(&DataType::Decimal(Some(10), Some(2)))
+            .unwrap();
+        let coords = StructChunked::new(
+            "coords",
+            &[
+                Series::new("x", [1i32]),
+                Series::new("y", [2.5f32]),
+                decimal,
+            ],
+        )
+        .unwrap()
+        .into_series();
+
+        let (x, y, z) = FieldMapping::default()
+            .unpack_xyz(&coords, 0, false)
+            .unwrap();
+        assert_eq!(x.f64().unwrap().get(0), Some(1.0));
+        assert_eq!(y.f64().unwrap().get(0), Some(2.5));
+        assert_eq!(z.f64().unwrap().get(0), Some(1.5));
+    }
 
-        let bbox2 = [
-            PointCoords { x: bbox2_1_x.unwrap(), y: bbox2_1_y.unwrap()},
-            PointCoords { x: bbox2_2_x.unwrap(), y: bbox2_2_y.unwrap()},
-            PointCoords { x: bbox2_3_x.unwrap(), y: bbox2_3_y.unwrap()},
-            PointCoords { x: bbox2_4_x.unwrap(), y: bbox2_4_y.unwrap()},
+    #[test]
+    fn test_list_and_array_coordinates() {
+        let list = Series::new(
+            "coords",
+            [
+                Some(Series::new("", [1.0, 2.0, 3.0])),
+                None,
+                Some(Series::new("", [4.0, 5.0, 6.0])),
+            ],
+        );
+        let (x, y, z) = FieldMapping::default().unpack_xyz(&list, 0, false).unwrap();
+        assert_eq!(x.name(), "x");
+        assert_eq!(x.f64().unwrap().get(0), Some(1.0));
+        assert_eq!(y.f64().unwrap().get(1), None);
+        assert_eq!(z.f64().unwrap().get(2), Some(6.0));
+
+        let err = FieldMapping::default().unpack_xyzw(&list, 0).unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+
+        let too_long = Series::new(
+            "coords",
+            [
+                Series::new("", [1.0, 2.0, 3.0]),
+                Series::new("", [4.0, 5.0, 6.0, 7.0]),
+            ],
+        );
+        let err = FieldMapping::default()
+            .unpack_xyz(&too_long, 0, false)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+
+        let array = Series::new(
+            "coords",
+            [
+                Series::new("", [1i32, 2]),
+                Series::new("", [3i32, 4]),
+                Series::new("", [5i32, 6]),
+            ],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Int32), 2))
+        .unwrap()
+        .slice(1, 2);
+        let (lon, lat) = FieldMapping::default().unpack_lonlat(&array, 0).unwrap();
+        assert_eq!(lon.f64().unwrap().get(0), Some(3.0));
+        assert_eq!(lat.f64().unwrap().get(1), Some(6.0));
+
+        let err = FieldMapping::default()
+            .unpack_xyz(&array, 0, false)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+    }
+
+    #[test]
+    fn test_unpack_lonlat_matches_unpack_xyz() {
+        let lla = Series::new("coords", [Series::new("", [10.0, 20.0, 30.0])]);
+        let (lon, lat) = FieldMapping::default().unpack_lonlat(&lla, 0).unwrap();
+        let (lon3, lat3, _) = FieldMapping::default().unpack_xyz(&lla, 0, true).unwrap();
+        assert!(lon.series_equal(&lon3) && lat.series_equal(&lat3));
+
+        let too_long = Series::new("coords", [Series::new("", [10.0, 20.0, 30.0, 40.0])]);
+        let err = FieldMapping::default()
+            .unpack_lonlat(&too_long, 0)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+
+        let fields = FieldMapping(vec![HashMap::from([(
+            "lon".to_string(),
+            "lng".to_string(),
+        )])]);
+        let renamed = StructChunked::new(
+            "point",
+            &[Series::new("lng", [10.0]), Series::new("lat", [20.0])],
+        )
+        .unwrap()
+        .into_series();
+        let (lon, _) = fields.unpack_lonlat(&renamed, 0).unwrap();
+        assert_eq!(lon.f64().unwrap().get(0), Some(10.0));
+
+        let missing = StructChunked::new("point", &[Series::new("lon", [10.0])])
+            .unwrap()
+            .into_series();
+        let err = FieldMapping::default()
+            .unpack_lonlat(&missing, 0)
+            .unwrap_err();
+        let err3 = FieldMapping::default()
+            .unpack_xyz(&missing, 0, true)
+            .unwrap_err();
+        assert_eq!(err.to_string(), err3.to_string());
+    }
+
+    #[test]
+    fn test_coordinates_to_array() {
+        let values = vec![Some(1.0), Some(2.0), None, None, Some(3.0), None];
+        let array = coordinates_to_array("coords", 2, values);
+        assert_eq!(
+            array.dtype(),
+            &DataType::Array(Box::new(DataType::Float64), 2)
+        );
+        assert_eq!(array.null_count(), 1);
+
+        let (x, y) = FieldMapping::default().unpack_xy(&array, 0).unwrap();
+        let x: Vec<Option<f64>> = x.f64().unwrap().into_iter().collect();
+        let y: Vec<Option<f64>> = y.f64().unwrap().into_iter().collect();
+        assert_eq!(x, vec![Some(1.0), None, Some(3.0)]);
+        assert_eq!(y, vec![Some(2.0), None, None]);
+    }
+
+    #[test]
+    fn test_transform_matrices() {
+        let identity = [
+            1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 1.0,
         ];
+        let matrices = Series::new(
+            "matrix",
+            [
+                Some(Series::new("", identity)),
+                Some(Series::new("", &identity[..12])),
+                None,
+            ],
+        );
+        let parsed = transform_matrices(&matrices).unwrap();
+        assert_eq!(parsed[0].as_deref(), Some(&identity[..]));
+        assert_eq!(parsed[1].as_ref().map(Vec::len), Some(12));
+        assert_eq!(parsed[2], None);
+
+        let wrong = Series::new("matrix", [Series::new("", [1.0; 9])]);
+        let err = transform_matrices(&wrong).unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+    }
 
-        let distance = bboxes_2d_elementwise(bbox1, bbox2);
+    #[test]
+    fn test_quat_rotation_matrices() {
+        let quat = Series::new("quat", [Some(Series::new("", [0.0, 0.0, 0.0, 1.0])), None]);
+        let matrices = quat_rotation_matrices(&[quat], &FieldMapping::default()).unwrap();
+        let lists = matrices
+            .cast(&DataType::List(Box::new(DataType::Float64)))
+            .unwrap();
+        let identity = lists.list().unwrap().get_as_series(0).unwrap();
+        assert_eq!(
+            Vec::from(identity.f64().unwrap()),
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0].map(Some)
+        );
+        assert_eq!(lists.null_count(), 1);
+    }
 
-        distances.append_value(distance);
+    #[test]
+    fn test_interpolate_points() {
+        let points = StructChunked::new(
+            "coords",
+            &[
+                Series::new("x", [2.0, 2.0, 2.0]),
+                Series::new("y", [4.0, 4.0, 4.0]),
+                Series::new("z", [6.0, 6.0, 6.0]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        let other = StructChunked::new(
+            "coords",
+            &[
+                Series::new("x", [10.0, 10.0, 10.0]),
+                Series::new("y", [20.0, 20.0, 20.0]),
+                Series::new("z", [30.0, 30.0, 30.0]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        let kwargs = |extrapolate| TransformInterpolateKwargs {
+            extrapolate,
+            fields: FieldMapping::default(),
+        };
+        let interpolate = |coef: Series, extrapolate| {
+            let inputs = [points.clone(), other.clone(), coef];
+            interpolate_points(&inputs, &kwargs(extrapolate))
+        };
+        let column = |out: &Series, field| {
+            let values = out.struct_().unwrap().field_by_name(field).unwrap();
+            Vec::from(values.f64().unwrap())
+        };
+
+        // `coef` weighs the first point: one value per row, with null rows.
+        let out = interpolate(Series::new("coef", [Some(0.25), Some(1.0), None]), false).unwrap();
+        assert_eq!(column(&out, "x"), [Some(8.0), Some(2.0), None]);
+        assert_eq!(column(&out, "z"), [Some(24.0), Some(6.0), None]);
+
+        // A literal is broadcast to every row.
+        let out = interpolate(Series::new("coef", [0.5f32]), false).unwrap();
+        assert_eq!(column(&out, "y"), [Some(12.0); 3]);
+
+        let err = interpolate(Series::new("coef", [0.5, 1.5, 0.5]), false).unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+        assert!(err.to_string().contains("row 1"));
+
+        let out = interpolate(Series::new("coef", [1.5, -0.5, 0.5]), true).unwrap();
+        assert_eq!(column(&out, "x"), [Some(-2.0), Some(14.0), Some(6.0)]);
     }
 
-    let ser_out_distances = distances.finish().into_series();
+    #[test]
+    fn test_interpolate_pose() {
+        let pose = pose_struct(
+            "pose",
+            &[Some([0.0, 0.0, 0.0, 1.0]), Some([0.0, 0.0, 0.0, 1.0])],
+            &[Some([0.0, 0.0, 0.0]), None],
+        )
+        .unwrap();
+        let other_offset = Series::new("offset", [Series::new("", [2.0, 4.0, 6.0])]);
+        let other_rotation = Series::new("rotation", [Series::new("", [0.0, 0.0, 1.0, 0.0])]);
+        let t = Series::new("t", [0.25f32]);
+
+        let inputs = [pose, other_offset, other_rotation, t];
+        let out = interpolate_poses(&inputs, &FieldMapping::default()).unwrap();
+        let out = out.struct_().unwrap();
+        let offset = out.field_by_name("offset").unwrap();
+        let rotation = out.field_by_name("rotation").unwrap();
+
+        let offset = offset.struct_().unwrap();
+        for (field, expected) in [("x", 0.5), ("y", 1.0), ("z", 1.5)] {
+            let values = offset.field_by_name(field).unwrap();
+            assert_eq!(Vec::from(values.f64().unwrap()), [Some(expected), None]);
+        }
 
-    Ok(ser_out_distances)
+        // A quarter of the half turn about z: 45 degrees.
+        let half_angle = std::f64::consts::PI / 8.0;
+        let rotation = rotation.struct_().unwrap();
+        for (field, expected) in [
+            ("x", 0.0),
+            ("y", 0.0),
+            ("z", half_angle.sin()),
+            ("w", half_angle.cos()),
+        ] {
+            let values = rotation.field_by_name(field).unwrap();
+            let value = values.f64().unwrap().get(0).unwrap();
+            assert!((value - expected).abs() < 1e-12, "{}: {}", field, value);
+        }
+    }
+
+    #[test]
+    fn test_poses_at() {
+        let identity = Some([0.0, 0.0, 0.0, 1.0]);
+        let pose = pose_struct(
+            "pose",
+            &[identity, identity, identity],
+            &[Some([0.0, 0.0, 0.0]), None, Some([10.0, 0.0, 0.0])],
+        )
+        .unwrap();
+        let times = Series::new("ts", [0i64, 1_000, 2_000])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let query = Series::new("query", [Some(500_000i64), Some(3_000_000), None])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+
+        let kwargs = PoseAtKwargs {
+            max_gap: Some(2_500_000.0),
+            extrapolate: "linear".to_string(),
+            fields: FieldMapping::default(),
+        };
+        let inputs = [pose.clone(), times.clone(), query.clone()];
+        let out = poses_at(&inputs, &kwargs).unwrap();
+        let offset = out.struct_().unwrap().field_by_name("offset").unwrap();
+        let x = offset.struct_().unwrap().field_by_name("x").unwrap();
+        // The null sample at 1s is skipped, so 0.5s falls between 0s and 2s.
+        assert_eq!(Vec::from(x.f64().unwrap()), [Some(2.5), Some(15.0), None]);
+
+        let kwargs = PoseAtKwargs {
+            max_gap: Some(1_000_000.0),
+            ..kwargs
+        };
+        let out = poses_at(&inputs, &kwargs).unwrap();
+        let offset = out.struct_().unwrap().field_by_name("offset").unwrap();
+        assert_eq!(offset.null_count(), 3);
+
+        let unsorted = times.reverse();
+        let err = poses_at(&[pose, unsorted, query], &kwargs).unwrap_err();
+        assert!(err.to_string().contains("sorted"));
+    }
+
+    #[test]
+    fn test_utm_hemisphere_and_zone_numbers() {
+        // 35°N lies in latitude band S, which is in the northern hemisphere.
+        let utm = lla_to_utm_full_elementwise(33.0, 35.0, 0.0, 36);
+        assert_eq!(utm.zone_letter, Some('S'));
+        let northern = parse_utm_hemisphere(&utm.zone_letter.unwrap().to_string()).unwrap();
+        let (lon, lat, _) = utm_to_lla_elementwise(utm.easting, utm.northing, 0.0, 36, northern);
+        assert!((lon - 33.0).abs() < 1e-6 && (lat - 35.0).abs() < 1e-6);
+
+        // The equator is in band N, with no false northing.
+        let utm = lla_to_utm_full_elementwise(33.0, 0.0, 0.0, 36);
+        assert_eq!(utm.zone_letter, Some('N'));
+        assert!(utm.northern);
+        assert!(utm.northing.abs() < 1e-6);
+        let northern = parse_utm_hemisphere(&utm.zone_letter.unwrap().to_string()).unwrap();
+        let (lon, lat, _) = utm_to_lla_elementwise(utm.easting, utm.northing, 0.0, 36, northern);
+        assert!((lon - 33.0).abs() < 1e-6 && lat.abs() < 1e-6);
+
+        assert!(parse_utm_hemisphere("s").unwrap());
+        assert!(!parse_utm_hemisphere("South").unwrap());
+        assert!(!parse_utm_hemisphere("C").unwrap());
+        assert!(parse_utm_hemisphere("A").is_err());
+        assert!(parse_utm_hemisphere("sud").is_err());
+
+        let zones = utm_zone_numbers(&Series::new("zone", [Some(33i32), None]), 2).unwrap();
+        assert_eq!(zones.u8().unwrap().get(0), Some(33));
+        assert_eq!(zones.u8().unwrap().get(1), None);
+        for zone in [0i64, 61, 300, -1] {
+            let err = utm_zone_numbers(&Series::new("zone", [zone]), 1).unwrap_err();
+            assert!(err.to_string().contains("between 1 and 60"), "{}", zone);
+        }
+    }
+
+    #[test]
+    fn test_distance_kernel_null_policies() {
+        let x1 = Series::new("x", [Some(0.0), Some(1.0)]);
+        let y1 = Series::new("y", [Some(0.0), None]);
+        let x2 = Series::new("x", [3.0, 1.0]);
+        let y2 = Series::new("y", [4.0, 1.0]);
+        let distances = |null_policy| {
+            apply_distance_kernel(
+                [&x1, &y1, &x2, &y2],
+                null_policy,
+                "distance",
+                |[x1, y1, x2, y2]| euclidean_2d_elementwise(x1, y1, x2, y2),
+            )
+        };
+
+        let propagated = distances(NullPolicy::Propagate).unwrap();
+        assert_eq!(propagated.f64().unwrap().to_vec(), [Some(5.0), None]);
+        let zeroed = distances(NullPolicy::Zero).unwrap();
+        assert_eq!(zeroed.f64().unwrap().to_vec(), [Some(5.0), Some(0.0)]);
+        let err = distances(NullPolicy::Raise).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("null value in field `y` of the first point at row 1"));
+    }
+
+    #[test]
+    fn test_bearing_null_policies() {
+        let points = StructChunked::new(
+            "points",
+            &[
+                Series::new("lon", [Some(0.0), None]),
+                Series::new("lat", [0.0, 0.0]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        let other = StructChunked::new(
+            "other",
+            &[
+                Series::new("lon", [1.0, 1.0]),
+                Series::new("lat", [0.0, 0.0]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        let wgs84 = ReferenceEllipsoid::WGS84;
+        let geodesic = Geodesic::new(wgs84.semi_major_axis, wgs84.flattening);
+        let bearings = |null_policy| {
+            let fields = FieldMapping::default();
+            geodesic_bearings(&points, &other, &fields, &geodesic, null_policy)
+        };
+        let initial = |out: Series| {
+            let initial = out.struct_().unwrap().field_by_name("initial").unwrap();
+            initial.f64().unwrap().to_vec()
+        };
+
+        assert_eq!(
+            initial(bearings(NullPolicy::Propagate).unwrap()),
+            [Some(90.0), None]
+        );
+        assert_eq!(
+            initial(bearings(NullPolicy::Zero).unwrap()),
+            [Some(90.0), Some(0.0)]
+        );
+        let err = bearings(NullPolicy::Raise).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("null value in field `lon` of the first point at row 1"));
+    }
 }
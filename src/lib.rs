@@ -1,6 +1,8 @@
 mod coord_transforms;
 mod distance;
+mod ellipsoid;
 mod expressions;
+mod quaternions;
 mod s2_functions;
 
 #[cfg(target_os = "linux")]
@@ -0,0 +1,231 @@
+extern crate nalgebra as na;
+
+use na::{Matrix3, Quaternion, Rotation3, Unit, UnitQuaternion, Vector3, Vector4};
+
+// Quaternions are passed around as `[x, y, z, w]`, the order of their struct fields.
+
+fn to_quaternion(q: [f64; 4]) -> Quaternion<f64> {
+    Quaternion::from_vector(Vector4::from(q))
+}
+
+fn from_quaternion(q: Quaternion<f64>) -> [f64; 4] {
+    [q.i, q.j, q.k, q.w]
+}
+
+/// Hamilton product `q * other`: rotating by the result is rotating by `other` first,
+/// then by `q`.
+pub fn quat_multiply_elementwise(q: [f64; 4], other: [f64; 4]) -> [f64; 4] {
+    from_quaternion(to_quaternion(q) * to_quaternion(other))
+}
+
+pub fn quat_conjugate_elementwise(q: [f64; 4]) -> [f64; 4] {
+    from_quaternion(to_quaternion(q).conjugate())
+}
+
+/// `None` for the zero quaternion, which has no inverse.
+pub fn quat_inverse_elementwise(q: [f64; 4]) -> Option<[f64; 4]> {
+    to_quaternion(q).try_inverse().map(from_quaternion)
+}
+
+/// `None` for the zero quaternion, which cannot be normalized.
+pub fn quat_normalize_elementwise(q: [f64; 4]) -> Option<[f64; 4]> {
+    let quat = to_quaternion(q);
+    match quat.norm() {
+        norm if norm > 0.0 => Some(from_quaternion(quat / norm)),
+        _ => None,
+    }
+}
+
+/// Angle (in radians, within [0, pi]) of the rotation between two orientations.
+pub fn quat_angle_between_elementwise(q: [f64; 4], other: [f64; 4]) -> f64 {
+    let q = UnitQuaternion::from_quaternion(to_quaternion(q));
+    let other = UnitQuaternion::from_quaternion(to_quaternion(other));
+    q.angle_to(&other)
+}
+
+pub fn quat_rotate_vector_elementwise(q: [f64; 4], vector: [f64; 3]) -> [f64; 3] {
+    let q = UnitQuaternion::from_quaternion(to_quaternion(q));
+    q.transform_vector(&Vector3::from(vector)).into()
+}
+
+/// Spherical linear interpolation from `q` (`t = 0`) to `other` (`t = 1`) along the
+/// shortest arc; `t` outside [0, 1] extrapolates.
+pub fn quat_slerp_elementwise(q: [f64; 4], other: [f64; 4], t: f64) -> [f64; 4] {
+    let q = UnitQuaternion::from_quaternion(to_quaternion(q));
+    let mut other = UnitQuaternion::from_quaternion(to_quaternion(other));
+    if q.coords.dot(&other.coords) < 0.0 {
+        other = UnitQuaternion::new_unchecked(-other.into_inner());
+    }
+    // `delta` rotates `q` onto `other` and is at most half a turn away from the identity.
+    let delta = q.inverse() * other;
+    from_quaternion((q * delta.powf(t)).into_inner())
+}
+
+/// Axis indices (0 for X, 1 for Y, 2 for Z) of a Tait-Bryan rotation order such as
+/// `"XYZ"` or `"zyx"`.
+pub fn parse_euler_order(order: &str) -> Option<[usize; 3]> {
+    let axes: Vec<usize> = order
+        .chars()
+        .map(|axis| match axis.to_ascii_uppercase() {
+            'X' => Some(0),
+            'Y' => Some(1),
+            'Z' => Some(2),
+            _ => None,
+        })
+        .collect::<Option<Vec<usize>>>()?;
+    match axes[..] {
+        [a, b, c] if a != b && b != c && a != c => Some([a, b, c]),
+        _ => None,
+    }
+}
+
+/// Quaternion of successive rotations by `angles` (in radians, about X, Y and Z
+/// respectively) in the sequence `order`. Extrinsic rotations are about the fixed
+/// axes, intrinsic ones about the axes rotated by the previous steps.
+pub fn euler_angles_to_quat_elementwise(
+    angles: [f64; 3],
+    order: [usize; 3],
+    intrinsic: bool,
+) -> [f64; 4] {
+    let steps = order.map(|axis| {
+        let mut axis_vector = Vector3::zeros();
+        axis_vector[axis] = 1.0;
+        UnitQuaternion::from_axis_angle(&Unit::new_unchecked(axis_vector), angles[axis])
+    });
+    let quat = if intrinsic {
+        steps[0] * steps[1] * steps[2]
+    } else {
+        steps[2] * steps[1] * steps[0]
+    };
+    from_quaternion(quat.into_inner())
+}
+
+/// Quaternion of a rotation by `[x, y, z, angle]` (angle in radians); `None` for a
+/// zero-length axis.
+pub fn axis_angle_to_quat_elementwise(axis_angle: [f64; 4]) -> Option<[f64; 4]> {
+    let [x, y, z, angle] = axis_angle;
+    let axis = Unit::try_new(Vector3::new(x, y, z), 0.0)?;
+    Some(from_quaternion(
+        UnitQuaternion::from_axis_angle(&axis, angle).into_inner(),
+    ))
+}
+
+/// Unit axis and angle (in radians, within [0, pi]) as `[x, y, z, angle]`; the
+/// identity rotation gives the X axis and a zero angle.
+pub fn quat_to_axis_angle_elementwise(q: [f64; 4]) -> [f64; 4] {
+    let quat = UnitQuaternion::from_quaternion(to_quaternion(q));
+    match quat.axis_angle() {
+        Some((axis, angle)) => [axis.x, axis.y, axis.z, angle],
+        None => [1.0, 0.0, 0.0, 0.0],
+    }
+}
+
+/// Quaternion of a 3x3 rotation matrix given row by row; the matrix is first
+/// replaced by the closest rotation.
+pub fn rotation_matrix_to_quat_elementwise(matrix: &[f64]) -> [f64; 4] {
+    let matrix = Matrix3::from_row_slice(matrix);
+    let rotation = Rotation3::from_matrix(&matrix);
+    from_quaternion(UnitQuaternion::from_rotation_matrix(&rotation).into_inner())
+}
+
+/// 3x3 rotation matrix, row by row.
+pub fn quat_to_rotation_matrix_elementwise(q: [f64; 4]) -> [f64; 9] {
+    let quat = UnitQuaternion::from_quaternion(to_quaternion(q));
+    let matrix = quat.to_rotation_matrix().into_inner();
+    std::array::from_fn(|i| matrix[(i / 3, i % 3)])
+}
+
+#[cfg(test)]
+mod quaternions_tests {
+    use crate::quaternions::{
+        axis_angle_to_quat_elementwise, euler_angles_to_quat_elementwise, parse_euler_order,
+        quat_angle_between_elementwise, quat_conjugate_elementwise, quat_inverse_elementwise,
+        quat_multiply_elementwise, quat_normalize_elementwise, quat_rotate_vector_elementwise,
+        quat_slerp_elementwise, quat_to_axis_angle_elementwise,
+        quat_to_rotation_matrix_elementwise, rotation_matrix_to_quat_elementwise,
+    };
+    use nalgebra::UnitQuaternion;
+    use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2};
+
+    // 90 degrees around z.
+    const QUAT_Z_90: [f64; 4] = [0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2];
+
+    fn assert_close<const N: usize>(left: [f64; N], right: [f64; N]) {
+        for (l, r) in left.iter().zip(right) {
+            assert!((l - r).abs() < 1e-12, "{:?} != {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn test_quat_algebra() {
+        let q_z_180 = quat_multiply_elementwise(QUAT_Z_90, QUAT_Z_90);
+        assert_close(q_z_180, [0.0, 0.0, 1.0, 0.0]);
+
+        let q = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(quat_conjugate_elementwise(q), [-1.0, -2.0, -3.0, 4.0]);
+        let inverse = quat_inverse_elementwise(q).unwrap();
+        assert_close(quat_multiply_elementwise(q, inverse), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(quat_inverse_elementwise([0.0; 4]), None);
+
+        let normalized = quat_normalize_elementwise([0.0, 0.0, 2.0, 2.0]).unwrap();
+        assert_close(normalized, QUAT_Z_90);
+        assert_eq!(quat_normalize_elementwise([0.0; 4]), None);
+    }
+
+    #[test]
+    fn test_quat_rotations() {
+        let angle = quat_angle_between_elementwise([0.0, 0.0, 0.0, 1.0], QUAT_Z_90);
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+
+        let rotated = quat_rotate_vector_elementwise(QUAT_Z_90, [1.0, 0.0, 5.0]);
+        assert_close(rotated, [0.0, 1.0, 5.0]);
+    }
+
+    #[test]
+    fn test_euler_angles_to_quat() {
+        let angles = [0.1, -0.4, 2.0];
+        let expected = UnitQuaternion::from_euler_angles(angles[0], angles[1], angles[2]);
+        let expected = [expected.i, expected.j, expected.k, expected.w];
+
+        let xyz = parse_euler_order("XYZ").unwrap();
+        let zyx = parse_euler_order("zyx").unwrap();
+        assert_close(
+            euler_angles_to_quat_elementwise(angles, xyz, false),
+            expected,
+        );
+        assert_close(
+            euler_angles_to_quat_elementwise(angles, zyx, true),
+            expected,
+        );
+        assert_eq!(parse_euler_order("XYX"), None);
+        assert_eq!(parse_euler_order("XY"), None);
+    }
+
+    #[test]
+    fn test_axis_angle_and_matrix() {
+        let quat = axis_angle_to_quat_elementwise([0.0, 0.0, 2.0, FRAC_PI_2]).unwrap();
+        assert_close(quat, QUAT_Z_90);
+        assert_close(
+            quat_to_axis_angle_elementwise(quat),
+            [0.0, 0.0, 1.0, FRAC_PI_2],
+        );
+        assert_eq!(axis_angle_to_quat_elementwise([0.0, 0.0, 0.0, 1.0]), None);
+
+        let matrix = quat_to_rotation_matrix_elementwise(QUAT_Z_90);
+        assert_close(matrix, [0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_close(rotation_matrix_to_quat_elementwise(&matrix), QUAT_Z_90);
+    }
+
+    #[test]
+    fn test_quat_slerp() {
+        let identity = [0.0, 0.0, 0.0, 1.0];
+        let halfway = quat_slerp_elementwise(identity, QUAT_Z_90, 0.5);
+        let expected = axis_angle_to_quat_elementwise([0.0, 0.0, 1.0, FRAC_PI_2 / 2.0]).unwrap();
+        assert_close(halfway, expected);
+
+        // The same rotation given by the opposite quaternion takes the shortest arc too.
+        let negated = QUAT_Z_90.map(|v| -v);
+        assert_close(quat_slerp_elementwise(identity, negated, 0.5), expected);
+        assert_close(quat_slerp_elementwise(identity, QUAT_Z_90, 0.0), identity);
+    }
+}
@@ -1,7 +1,16 @@
 extern crate s2;
+use s2::cap::Cap;
 use s2::cell::Cell;
-use s2::cellid::CellID;
+use s2::cellid::{CellID, MAX_LEVEL};
+use s2::edgeutil::simple_crossing;
 use s2::latlng::LatLng;
+use s2::point::Point;
+use s2::predicates::{robust_sign, Direction};
+use s2::r3::vector::Vector;
+use s2::rect::Rect;
+use s2::region::{Region, RegionCoverer};
+use s2::s1::{Angle, Rad};
+use std::f64::consts::FRAC_PI_2;
 
 pub fn lonlat_to_cellid_elementwise(lng: f64, lat: f64, level: u64) -> u64 {
     let cell_id = CellID::from(LatLng::from_degrees(lat, lng));
@@ -15,10 +24,33 @@ pub fn cellid_to_lonlat_elementwise(cellid: u64) -> (f64, f64) {
     (lng, lat)
 }
 
-#[allow(dead_code)]
-fn cell_area_elementwise(cellid: u64) -> f64 {
-    let cell = Cell::from(CellID(cellid));
-    cell.approx_area()
+/// How the area of a cell is computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellAreaKind {
+    Approx,
+    Exact,
+    Average,
+}
+
+impl CellAreaKind {
+    pub fn from_name(name: &str) -> Option<CellAreaKind> {
+        match name {
+            "approx" => Some(CellAreaKind::Approx),
+            "exact" => Some(CellAreaKind::Exact),
+            "average" => Some(CellAreaKind::Average),
+            _ => None,
+        }
+    }
+}
+
+/// Area of a cell on the unit sphere, in steradians; `None` for invalid cell IDs.
+pub fn cell_area_elementwise(cellid: u64, kind: CellAreaKind) -> Option<f64> {
+    let cell = Cell::from(valid_cellid(cellid)?);
+    Some(match kind {
+        CellAreaKind::Approx => cell.approx_area(),
+        CellAreaKind::Exact => cell.exact_area(),
+        CellAreaKind::Average => cell.average_area(),
+    })
 }
 
 pub fn cell_contains_point_elementwise(cellid: u64, point_lon: f64, point_lat: f64) -> bool {
@@ -35,10 +67,330 @@ pub fn cellid_to_vertices_elementwise(cellid: u64) -> Vec<(f64, f64)> {
         .collect::<Vec<(f64, f64)>>()
 }
 
+/// The cell ID, or `None` when it does not encode a valid cell.
+fn valid_cellid(cellid: u64) -> Option<CellID> {
+    let cell_id = CellID(cellid);
+    cell_id.is_valid().then_some(cell_id)
+}
+
+pub fn cellid_level_elementwise(cellid: u64) -> Option<u8> {
+    valid_cellid(cellid).map(|cell_id| cell_id.level() as u8)
+}
+
+/// Ancestor at `level`; `None` when `level` is deeper than the cell.
+pub fn cellid_parent_elementwise(cellid: u64, level: u64) -> Option<u64> {
+    let cell_id = valid_cellid(cellid)?;
+    (level <= cell_id.level()).then(|| cell_id.parent(level).0)
+}
+
+/// The four cells one level deeper; `None` for leaf cells.
+pub fn cellid_children_elementwise(cellid: u64) -> Option<Vec<u64>> {
+    let cell_id = valid_cellid(cellid)?;
+    (!cell_id.is_leaf()).then(|| cell_id.children().iter().map(|child| child.0).collect())
+}
+
+/// Cells of the same level sharing an edge with the cell: down, right, up, left.
+pub fn cellid_edge_neighbors_elementwise(cellid: u64) -> Option<Vec<u64>> {
+    let cell_id = valid_cellid(cellid)?;
+    Some(
+        cell_id
+            .edge_neighbors()
+            .iter()
+            .map(|neighbor| neighbor.0)
+            .collect(),
+    )
+}
+
+/// How many levels finer than the cell `all_neighbors` may go: a cell has about
+/// `4 * 2^k` neighbors `k` levels down.
+pub const MAX_NEIGHBOR_LEVEL_DIFF: u64 = 10;
+
+/// Distinct cells at `level` (no coarser than the cell and at most
+/// `MAX_NEIGHBOR_LEVEL_DIFF` levels finer) whose boundary touches the cell, sorted by
+/// cell ID; `None` for other levels.
+pub fn cellid_all_neighbors_elementwise(cellid: u64, level: u64) -> Option<Vec<u64>> {
+    let cell_id = valid_cellid(cellid)?;
+    if level < cell_id.level()
+        || level > MAX_LEVEL
+        || level - cell_id.level() > MAX_NEIGHBOR_LEVEL_DIFF
+    {
+        return None;
+    }
+    let mut neighbors: Vec<u64> = cell_id
+        .all_neighbors(level)
+        .iter()
+        .map(|neighbor| neighbor.0)
+        .collect();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    Some(neighbors)
+}
+
+/// Cells at `level` (coarser than the cell) sharing the vertex of the level-`level`
+/// ancestor closest to the cell, the ancestor included; `None` when `level` is not
+/// coarser than the cell.
+pub fn cellid_vertex_neighbors_elementwise(cellid: u64, level: u64) -> Option<Vec<u64>> {
+    let cell_id = valid_cellid(cellid)?;
+    (level < cell_id.level()).then(|| {
+        cell_id
+            .vertex_neighbors(level)
+            .iter()
+            .map(|neighbor| neighbor.0)
+            .collect()
+    })
+}
+
+/// Smallest leaf cell ID within the cell; `None` for invalid cell IDs.
+pub fn cellid_range_min_elementwise(cellid: u64) -> Option<u64> {
+    valid_cellid(cellid).map(|cell_id| cell_id.range_min().0)
+}
+
+/// Largest leaf cell ID within the cell; `None` for invalid cell IDs.
+pub fn cellid_range_max_elementwise(cellid: u64) -> Option<u64> {
+    valid_cellid(cellid).map(|cell_id| cell_id.range_max().0)
+}
+
+/// Hex token of a cell ID (trailing zeros stripped); `None` for invalid cell IDs.
+pub fn cellid_to_token_elementwise(cellid: u64) -> Option<String> {
+    valid_cellid(cellid).map(|cell_id| cell_id.to_token())
+}
+
+/// Cell ID of a hex token of up to 16 digits; `None` when the token is malformed or
+/// does not encode a valid cell.
+pub fn token_to_cellid_elementwise(token: &str) -> Option<u64> {
+    let valid_digits =
+        !token.is_empty() && token.len() <= 16 && token.chars().all(|c| c.is_ascii_hexdigit());
+    if !valid_digits {
+        return None;
+    }
+    valid_cellid(CellID::from_token(token).0).map(|cell_id| cell_id.0)
+}
+
+/// Levels and cell budget of a covering, and whether it is an interior covering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoveringOptions {
+    pub min_level: u8,
+    pub max_level: u8,
+    pub max_cells: usize,
+    pub interior: bool,
+}
+
+fn covering_elementwise<R: Region + 'static>(region: &R, options: CoveringOptions) -> Vec<u64> {
+    let coverer = RegionCoverer {
+        min_level: options.min_level,
+        max_level: options.max_level,
+        level_mod: 1,
+        max_cells: options.max_cells,
+    };
+    let cell_union = if options.interior {
+        coverer.interior_covering(region)
+    } else {
+        coverer.covering(region)
+    };
+    cell_union.0.iter().map(|cell_id| cell_id.0).collect()
+}
+
+/// Covering of the spherical cap around `(lng, lat)` with a radius given as an angle
+/// in radians.
+pub fn cap_covering_elementwise(
+    lng: f64,
+    lat: f64,
+    radius: f64,
+    options: CoveringOptions,
+) -> Vec<u64> {
+    let center = Point::from(LatLng::from_degrees(lat, lng));
+    let cap = Cap::from_center_angle(&center, &Angle::from(Rad(radius)));
+    covering_elementwise(&cap, options)
+}
+
+/// Covering of the lat/lng rectangle between the south-west corner `lo` and the
+/// north-east corner `hi`, both `(lng, lat)`; it crosses the antimeridian when the
+/// west longitude is greater than the east one.
+pub fn rect_covering_elementwise(
+    lo: (f64, f64),
+    hi: (f64, f64),
+    options: CoveringOptions,
+) -> Vec<u64> {
+    let rect = Rect::from_degrees(lo.1, lo.0, hi.1, hi.0);
+    covering_elementwise(&rect, options)
+}
+
+/// Whether `c` is left of the great circle from `a` to `b`; points on the circle count
+/// as left, so a ray through a polygon vertex crosses exactly one of its two edges.
+fn left_of(a: &Point, b: &Point, c: &Point) -> bool {
+    robust_sign(a, b, c) != Direction::Clockwise
+}
+
+/// Whether `a`, `b` and `c` are encountered in this order going counterclockwise
+/// around `o` (S2's `OrderedCCW`).
+fn ordered_ccw(a: &Point, b: &Point, c: &Point, o: &Point) -> bool {
+    let sum = [
+        robust_sign(b, o, a) != Direction::Clockwise,
+        robust_sign(c, o, b) != Direction::Clockwise,
+        robust_sign(a, o, c) == Direction::CounterClockwise,
+    ]
+    .iter()
+    .filter(|&&ccw| ccw)
+    .count();
+    sum >= 2
+}
+
+/// S2's `VertexCrossing` rule for edges AB and CD sharing a vertex: they cross there
+/// when AB is further counterclockwise around it than CD, so that a point on a chain
+/// of edges is crossed exactly once.
+fn vertex_crossing(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    if a == b || c == d {
+        false
+    } else if a == c {
+        b == d || ordered_ccw(&a.ortho(), d, b, a)
+    } else if b == d {
+        ordered_ccw(&b.ortho(), c, a, b)
+    } else if a == d {
+        b == c || ordered_ccw(&a.ortho(), c, b, a)
+    } else if b == c {
+        ordered_ccw(&b.ortho(), d, a, b)
+    } else {
+        false
+    }
+}
+
+/// Whether edge AB crosses edge CD, counting crossings at a shared vertex with
+/// `vertex_crossing` and vertices lying on the other edge with `left_of` (S2's
+/// `EdgeOrVertexCrossing`).
+fn edge_or_vertex_crossing(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    if a == c || a == d || b == c || b == d {
+        return vertex_crossing(a, b, c, d);
+    }
+    if a == b || c == d {
+        return false;
+    }
+    // Triangles ACB, BDA, CBD and DAC must all have the same orientation.
+    let acb = !left_of(a, b, c);
+    acb == left_of(a, b, d) && acb != left_of(c, d, b) && acb == left_of(c, d, a)
+}
+
+/// A polygon with geodesic edges between consecutive vertices (the last vertex joins
+/// the first). It must fit in a hemisphere: its interior is the side not containing
+/// the point opposite to the vertex centroid.
+struct SphericalPolygon {
+    vertices: Vec<Point>,
+    cap: Cap,
+    outside: Point,
+}
+
+impl SphericalPolygon {
+    fn new(vertices: Vec<Point>) -> Option<SphericalPolygon> {
+        if vertices.len() < 3 {
+            return None;
+        }
+        // `Point::from_coords(0.0, 0.0, 0.0)` is S2's origin point, not the zero vector.
+        let zero = Point(Vector {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        });
+        let sum = vertices.iter().fold(zero, |sum, &vertex| sum + vertex);
+        if sum.norm() == 0.0 {
+            return None;
+        }
+        let center = sum.normalize();
+        let radius = vertices
+            .iter()
+            .map(|vertex| center.distance(vertex).rad())
+            .fold(0.0, f64::max);
+        // Caps smaller than a hemisphere are convex, so they contain the edges too.
+        if radius >= FRAC_PI_2 {
+            return None;
+        }
+        Some(SphericalPolygon {
+            vertices,
+            cap: Cap::from_center_angle(&center, &Angle::from(Rad(radius))),
+            outside: center * -1.0,
+        })
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    fn crosses(&self, a: &Point, b: &Point) -> bool {
+        self.edges().any(|(c, d)| simple_crossing(a, b, c, d))
+    }
+
+    fn contains_point(&self, point: &Point) -> bool {
+        let crossings = self
+            .edges()
+            .filter(|(c, d)| edge_or_vertex_crossing(&self.outside, point, c, d))
+            .count();
+        crossings % 2 == 1
+    }
+
+    fn crosses_cell(&self, cell: &Cell) -> bool {
+        let vertices = cell.vertices();
+        (0..4).any(|k| self.crosses(&vertices[k], &vertices[(k + 1) % 4]))
+    }
+}
+
+impl Region for SphericalPolygon {
+    fn cap_bound(&self) -> Cap {
+        self.cap.clone()
+    }
+
+    fn contains_cell(&self, cell: &Cell) -> bool {
+        // The polygon is closed here: cell vertices on polygon vertices are inside.
+        cell.vertices()
+            .iter()
+            .all(|vertex| self.vertices.contains(vertex) || self.contains_point(vertex))
+            && !self.crosses_cell(cell)
+    }
+
+    fn intersects_cell(&self, cell: &Cell) -> bool {
+        cell.vertices()
+            .iter()
+            .any(|vertex| self.contains_point(vertex))
+            || self
+                .vertices
+                .iter()
+                .any(|vertex| cell.contains_point(vertex))
+            || self.crosses_cell(cell)
+    }
+}
+
+/// Covering of the polygon with `(lng, lat)` vertices; `None` for polygons with fewer
+/// than three vertices or not fitting in a hemisphere.
+pub fn polygon_covering_elementwise(
+    vertices: &[(f64, f64)],
+    options: CoveringOptions,
+) -> Option<Vec<u64>> {
+    let points = vertices
+        .iter()
+        .map(|&(lng, lat)| Point::from(LatLng::from_degrees(lat, lng)))
+        .collect();
+    let polygon = SphericalPolygon::new(points)?;
+    Some(covering_elementwise(&polygon, options))
+}
+
 #[cfg(test)]
 mod s2_tests {
 
-    use crate::s2_functions::{cellid_to_lonlat_elementwise, lonlat_to_cellid_elementwise};
+    use crate::s2_functions::{
+        cap_covering_elementwise, cell_area_elementwise, cell_contains_point_elementwise,
+        cellid_all_neighbors_elementwise, cellid_children_elementwise,
+        cellid_edge_neighbors_elementwise, cellid_level_elementwise, cellid_parent_elementwise,
+        cellid_range_max_elementwise, cellid_range_min_elementwise, cellid_to_lonlat_elementwise,
+        cellid_to_token_elementwise, cellid_vertex_neighbors_elementwise,
+        lonlat_to_cellid_elementwise, polygon_covering_elementwise, rect_covering_elementwise,
+        token_to_cellid_elementwise, CellAreaKind, CoveringOptions, MAX_NEIGHBOR_LEVEL_DIFF,
+    };
+    use crate::s2_functions::{covering_elementwise, SphericalPolygon};
+    use s2::cell::Cell;
+    use s2::cellid::CellID;
+    use s2::latlng::LatLng;
+    use s2::point::Point;
+    use s2::region::Region;
+    use std::f64::consts::PI;
 
     #[test]
     fn test_lonlat_to_cellid() {
@@ -68,4 +420,204 @@ mod s2_tests {
 
         assert_eq!(lonlat_to_cellid_elementwise(lon, lat, 30), cellid)
     }
+
+    #[test]
+    fn test_cellid_hierarchy() {
+        let cellid: u64 = 5095400969591719543;
+        let parent = cellid_parent_elementwise(cellid, 12).unwrap();
+
+        assert_eq!(cellid_level_elementwise(cellid), Some(30));
+        assert_eq!(cellid_level_elementwise(parent), Some(12));
+        assert_eq!(
+            parent,
+            lonlat_to_cellid_elementwise(36.077147686805766, 56.783927007002866, 12)
+        );
+        assert_eq!(cellid_parent_elementwise(parent, 13), None);
+        assert_eq!(cellid_level_elementwise(0), None);
+
+        let children = cellid_children_elementwise(parent).unwrap();
+        assert_eq!(children.len(), 4);
+        assert!(children
+            .iter()
+            .all(|&child| cellid_parent_elementwise(child, 12) == Some(parent)));
+        assert_eq!(cellid_children_elementwise(cellid), None);
+    }
+
+    #[test]
+    fn test_cellid_neighbors() {
+        let cellid = lonlat_to_cellid_elementwise(36.077147686805766, 56.783927007002866, 12);
+
+        let edge = cellid_edge_neighbors_elementwise(cellid).unwrap();
+        let all = cellid_all_neighbors_elementwise(cellid, 12).unwrap();
+        assert_eq!(all.len(), 8);
+        assert!(edge.iter().all(|neighbor| all.contains(neighbor)));
+        assert_eq!(cellid_all_neighbors_elementwise(cellid, 11), None);
+
+        let finer = cellid_all_neighbors_elementwise(cellid, 14).unwrap();
+        assert_eq!(finer.len(), 20);
+        assert!(finer.windows(2).all(|pair| pair[0] < pair[1]));
+        let too_fine = 12 + MAX_NEIGHBOR_LEVEL_DIFF + 1;
+        assert_eq!(cellid_all_neighbors_elementwise(cellid, too_fine), None);
+
+        let vertex = cellid_vertex_neighbors_elementwise(cellid, 11).unwrap();
+        assert_eq!(vertex.len(), 4);
+        assert!(vertex.contains(&cellid_parent_elementwise(cellid, 11).unwrap()));
+        assert_eq!(cellid_vertex_neighbors_elementwise(cellid, 12), None);
+    }
+
+    #[test]
+    fn test_cellid_ranges() {
+        let leaf: u64 = 5095400969591719543;
+        let parent = cellid_parent_elementwise(leaf, 10).unwrap();
+        let range_min = cellid_range_min_elementwise(parent).unwrap();
+        let range_max = cellid_range_max_elementwise(parent).unwrap();
+
+        assert!(range_min <= leaf && leaf <= range_max);
+        assert_eq!(cellid_parent_elementwise(range_min, 10), Some(parent));
+        assert_eq!(cellid_parent_elementwise(range_max, 10), Some(parent));
+        assert_eq!(cellid_level_elementwise(range_min), Some(30));
+        assert_eq!(cellid_range_min_elementwise(leaf), Some(leaf));
+        assert_eq!(cellid_range_max_elementwise(leaf), Some(leaf));
+        assert_eq!(cellid_range_min_elementwise(0), None);
+    }
+
+    #[test]
+    fn test_cell_area() {
+        let face = cellid_parent_elementwise(5095400969591719543, 0).unwrap();
+        for kind in [
+            CellAreaKind::Approx,
+            CellAreaKind::Exact,
+            CellAreaKind::Average,
+        ] {
+            let area = cell_area_elementwise(face, kind).unwrap();
+            assert!((area - 4.0 * PI / 6.0).abs() < 1e-9, "{:?}: {}", kind, area);
+        }
+
+        let leaf = cell_area_elementwise(5095400969591719543, CellAreaKind::Exact).unwrap();
+        let approx = cell_area_elementwise(5095400969591719543, CellAreaKind::Approx).unwrap();
+        assert!((leaf - approx).abs() / leaf < 1e-3);
+        assert_eq!(cell_area_elementwise(0, CellAreaKind::Exact), None);
+    }
+
+    #[test]
+    fn test_cellid_tokens() {
+        let cellid: u64 = 5095400969591719543;
+        let parent = cellid_parent_elementwise(cellid, 10).unwrap();
+
+        assert_eq!(
+            cellid_to_token_elementwise(parent).as_deref(),
+            Some("46b681")
+        );
+        let token = cellid_to_token_elementwise(cellid).unwrap();
+        assert_eq!(token_to_cellid_elementwise(&token), Some(cellid));
+        assert_eq!(token_to_cellid_elementwise("46B681"), Some(parent));
+
+        assert_eq!(cellid_to_token_elementwise(0), None);
+        for token in ["", "X", "46b5zz", "+46b5", "46b5000000000000000", "46b2"] {
+            assert_eq!(token_to_cellid_elementwise(token), None, "{}", token);
+        }
+    }
+
+    fn covers(cellids: &[u64], lon: f64, lat: f64) -> bool {
+        cellids
+            .iter()
+            .any(|&cellid| cell_contains_point_elementwise(cellid, lon, lat))
+    }
+
+    #[test]
+    fn test_cap_and_rect_coverings() {
+        let options = CoveringOptions {
+            min_level: 4,
+            max_level: 16,
+            max_cells: 8,
+            interior: false,
+        };
+        // 1 km around a point, as an angle on the mean Earth sphere.
+        let cap = cap_covering_elementwise(36.08, 56.78, 1000.0 / 6371008.8, options);
+        assert!(!cap.is_empty() && cap.len() <= 8);
+        assert!(covers(&cap, 36.08, 56.78));
+        assert!(covers(&cap, 36.08, 56.785));
+        assert!(!covers(&cap, 36.2, 56.78));
+
+        let rect = rect_covering_elementwise((36.0, 56.7), (36.2, 56.8), options);
+        assert!(covers(&rect, 36.01, 56.71) && covers(&rect, 36.19, 56.79));
+        assert!(!covers(&rect, 37.0, 56.75));
+
+        let interior = CoveringOptions {
+            interior: true,
+            ..options
+        };
+        let rect_interior = rect_covering_elementwise((36.0, 56.7), (36.2, 56.8), interior);
+        assert!(covers(&rect_interior, 36.1, 56.75));
+        assert!(!covers(&rect_interior, 36.01, 56.81));
+    }
+
+    #[test]
+    fn test_polygon_covering() {
+        let options = CoveringOptions {
+            min_level: 4,
+            max_level: 18,
+            max_cells: 16,
+            interior: false,
+        };
+        // An L-shaped polygon: the notch around (36.15, 56.775) is outside.
+        let polygon = [
+            (36.0, 56.7),
+            (36.2, 56.7),
+            (36.2, 56.75),
+            (36.1, 56.75),
+            (36.1, 56.8),
+            (36.0, 56.8),
+        ];
+        let covering = polygon_covering_elementwise(&polygon, options).unwrap();
+        assert!(covers(&covering, 36.05, 56.75));
+        assert!(covers(&covering, 36.15, 56.72));
+        assert!(!covers(&covering, 36.3, 56.75));
+
+        let interior = CoveringOptions {
+            interior: true,
+            ..options
+        };
+        let interior = polygon_covering_elementwise(&polygon, interior).unwrap();
+        assert!(!interior.is_empty());
+        assert!(covers(&interior, 36.05, 56.75));
+        assert!(!covers(&interior, 36.15, 56.78));
+
+        assert_eq!(polygon_covering_elementwise(&polygon[..2], options), None);
+    }
+
+    #[test]
+    fn test_polygon_vertex_crossings() {
+        let point = |lng, lat| Point::from(LatLng::from_degrees(lat, lng));
+        // The ray from the point opposite to the centroid (lng 180 on the equator)
+        // to points on the equator runs exactly through the vertex at lng 10.
+        let diamond = SphericalPolygon::new(vec![
+            point(10.0, 0.0),
+            point(0.0, 10.0),
+            point(-10.0, 0.0),
+            point(0.0, -10.0),
+        ])
+        .unwrap();
+        assert!(diamond.contains_point(&point(5.0, 0.0)));
+        assert!(!diamond.contains_point(&point(15.0, 0.0)));
+
+        // A polygon with the vertices of a cell contains that cell, and only that
+        // cell of its level.
+        let cell_id = CellID(lonlat_to_cellid_elementwise(36.07, 56.78, 10));
+        let cell = Cell::from(cell_id);
+        let polygon = SphericalPolygon::new(cell.vertices().to_vec()).unwrap();
+        assert!(polygon.contains_cell(&cell));
+        assert!(polygon.contains_point(&cell.center()));
+        for neighbor in cell_id.edge_neighbors() {
+            assert!(!polygon.contains_cell(&Cell::from(neighbor)));
+            assert!(!polygon.contains_point(&Cell::from(neighbor).center()));
+        }
+        let options = CoveringOptions {
+            min_level: 10,
+            max_level: 10,
+            max_cells: 8,
+            interior: true,
+        };
+        assert_eq!(covering_elementwise(&polygon, options), vec![cell_id.0]);
+    }
 }